@@ -0,0 +1,43 @@
+//! Compares multiplication of `Mint` with the plain `%` operator and Barrett reduction.
+//!
+//! Run with `cargo bench --bench mint`.
+use algorithms::math::{DynamicMod, Mint, Mod107, Module, MOD_107};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Instant;
+
+static PLAIN_MODULE: AtomicU32 = AtomicU32::new(MOD_107);
+
+/// Module given at runtime which uses the default `%` multiplication.
+#[derive(Debug, Copy, Clone)]
+struct PlainDynamicMod;
+impl Module for PlainDynamicMod {
+    fn module() -> u32 {
+        PLAIN_MODULE.load(Ordering::Relaxed)
+    }
+}
+
+const N: usize = 50_000_000;
+
+fn bench<M: Module>(name: &str) {
+    let start = Instant::now();
+    let mut x: Mint<M> = Mint::from(1);
+    let mut y: Mint<M> = Mint::from(123_456_789);
+    for i in 0..N {
+        x *= y;
+        y += i;
+    }
+    println!("{:<18} {:>8.2?} (result: {})", name, start.elapsed(), x);
+}
+
+fn main() {
+    // read the module number at runtime so that the optimizer cannot see it
+    let m = std::env::args()
+        .filter_map(|arg| arg.parse().ok())
+        .next()
+        .unwrap_or(MOD_107);
+    DynamicMod::set_module(m);
+    PLAIN_MODULE.store(m, Ordering::Relaxed);
+    bench::<Mod107>("constant %");
+    bench::<PlainDynamicMod>("dynamic %");
+    bench::<DynamicMod>("dynamic Barrett");
+}
@@ -52,15 +52,15 @@ impl BitArray {
     }
 
     pub fn union(&mut self, other: &Self) {
-        self.mutate(other, |x, y| (x | y))
+        self.mutate(other, |x, y| x | y)
     }
 
     pub fn intersect(&mut self, other: &Self) {
-        self.mutate(other, |x, y| (x & y))
+        self.mutate(other, |x, y| x & y)
     }
 
     pub fn difference(&mut self, other: &Self) {
-        self.mutate(other, |x, y| (x & !y))
+        self.mutate(other, |x, y| x & !y)
     }
 
     fn mutate<F>(&mut self, other: &Self, op: F)
@@ -84,6 +84,7 @@ impl BitArray {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
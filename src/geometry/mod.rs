@@ -0,0 +1,15 @@
+//! two dimensional computational geometry
+//!
+//! * [`Point`](struct.Point.html) - point and vector with dot/cross products and orientation
+//! * [`segments_intersect`](fn.segments_intersect.html) - intersection test of two segments
+//! * [`area2`](fn.area2.html) - twice the signed area of a polygon
+//! * [`polygon_contains`](fn.polygon_contains.html) - point-in-polygon test
+//! * [`convex_hull`](fn.convex_hull.html) - convex hull by monotone chain
+//! * [`diameter`](fn.diameter.html) - farthest pair of points by rotating calipers
+//! * [`closest_pair`](fn.closest_pair.html) - closest pair of points by divide and conquer
+//!
+mod point;
+mod polygon;
+
+pub use self::point::{line_intersection, segments_intersect, Coord, Orientation, Point};
+pub use self::polygon::{area2, closest_pair, convex_hull, diameter, polygon_contains, Location};
@@ -0,0 +1,323 @@
+//! Points, vectors and segments in two dimensions
+use std::cmp;
+use std::fmt;
+use std::ops;
+
+/// Numeric types used as coordinates of [`Point`](struct.Point.html).
+///
+/// Predicates are exact for integer types as long as products of two coordinates do not overflow.
+/// Floating types are subject to rounding errors.
+pub trait Coord:
+    Copy
+    + PartialOrd
+    + fmt::Debug
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Neg<Output = Self>
+{
+    fn zero() -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty, $zero:expr;)*) => ($(
+        impl Coord for $t {
+            fn zero() -> $t {
+                $zero
+            }
+        }
+    )*)
+}
+
+impl_coord! {
+    i32, 0;
+    i64, 0;
+    i128, 0;
+    isize, 0;
+    f64, 0.0;
+}
+
+/// Point (or vector) on the plane.
+///
+/// Points are ordered lexicographically by `(x, y)`.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{Orientation, Point};
+/// let a = Point::new(1i64, 2);
+/// let b = Point::new(3i64, -1);
+/// assert_eq!(Point::new(4, 1), a + b);
+/// assert_eq!(1, a.dot(b));
+/// assert_eq!(-7, a.cross(b));
+/// assert_eq!(Orientation::Clockwise, Point::new(0, 0).orientation(a, b));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Direction of the turn `a -> b -> c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    Collinear,
+    CounterClockwise,
+}
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    pub fn dot(self, other: Point<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// z-component of the cross product, which is positive if `other` is counterclockwise from `self`.
+    pub fn cross(self, other: Point<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Squared Euclidean norm.
+    pub fn norm2(self) -> T {
+        self.dot(self)
+    }
+
+    /// Squared Euclidean distance.
+    pub fn dist2(self, other: Point<T>) -> T {
+        (self - other).norm2()
+    }
+
+    /// Direction of the turn `self -> b -> c`.
+    pub fn orientation(self, b: Point<T>, c: Point<T>) -> Orientation {
+        let z = (b - self).cross(c - self);
+        if z > T::zero() {
+            Orientation::CounterClockwise
+        } else if z < T::zero() {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    /// Returns true if `self` is on the closed segment `ab`.
+    pub fn on_segment(self, a: Point<T>, b: Point<T>) -> bool {
+        self.orientation(a, b) == Orientation::Collinear && (a - self).dot(b - self) <= T::zero()
+    }
+
+    /// Compares `self` and `other` by the polar angle in `(-pi, pi]`.
+    ///
+    /// Both should not be the origin.
+    pub fn cmp_arg(self, other: Point<T>) -> cmp::Ordering {
+        // (-pi, 0), [0, pi) and {pi}
+        let half = |p: Point<T>| {
+            if p.y < T::zero() {
+                0
+            } else if p.y > T::zero() || p.x > T::zero() {
+                1
+            } else {
+                2
+            }
+        };
+        half(self)
+            .cmp(&half(other))
+            .then_with(|| T::zero().partial_cmp(&self.cross(other)).unwrap())
+    }
+}
+
+impl Point<f64> {
+    /// Euclidean norm.
+    pub fn abs(self) -> f64 {
+        self.norm2().sqrt()
+    }
+
+    /// Euclidean distance.
+    pub fn dist(self, other: Point<f64>) -> f64 {
+        (self - other).abs()
+    }
+
+    /// Polar angle in `(-pi, pi]`.
+    pub fn arg(self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// Rotates counterclockwise by `theta` radians around the origin.
+    pub fn rotate(self, theta: f64) -> Point<f64> {
+        let (s, c) = theta.sin_cos();
+        Point::new(self.x * c - self.y * s, self.x * s + self.y * c)
+    }
+}
+
+impl<T: Coord> ops::Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> ops::Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> ops::Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, k: T) -> Point<T> {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+impl<T: Coord> ops::Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coord> ops::AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> ops::SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+/// Returns true if the closed segments `a1 a2` and `b1 b2` share at least one point.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{segments_intersect, Point};
+/// let p = |x: i64, y: i64| Point::new(x, y);
+/// assert!(segments_intersect(p(0, 0), p(2, 2), p(0, 2), p(2, 0)));
+/// assert!(segments_intersect(p(0, 0), p(2, 2), p(2, 2), p(3, 0)));
+/// assert!(!segments_intersect(p(0, 0), p(1, 1), p(2, 2), p(3, 3)));
+/// ```
+pub fn segments_intersect<T: Coord>(
+    a1: Point<T>,
+    a2: Point<T>,
+    b1: Point<T>,
+    b2: Point<T>,
+) -> bool {
+    let sign = |o: Orientation| match o {
+        Orientation::Clockwise => -1,
+        Orientation::Collinear => 0,
+        Orientation::CounterClockwise => 1,
+    };
+    let d1 = sign(a1.orientation(a2, b1));
+    let d2 = sign(a1.orientation(a2, b2));
+    let d3 = sign(b1.orientation(b2, a1));
+    let d4 = sign(b1.orientation(b2, a2));
+    if d1 * d2 < 0 && d3 * d4 < 0 {
+        return true;
+    }
+    b1.on_segment(a1, a2) || b2.on_segment(a1, a2) || a1.on_segment(b1, b2) || a2.on_segment(b1, b2)
+}
+
+/// Returns the intersection of the lines through `a1 a2` and `b1 b2`, or `None` if they are parallel.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{line_intersection, Point};
+/// let p = |x: f64, y: f64| Point::new(x, y);
+/// assert_eq!(Some(p(1.0, 1.0)), line_intersection(p(0.0, 0.0), p(2.0, 2.0), p(0.0, 2.0), p(2.0, 0.0)));
+/// assert_eq!(None, line_intersection(p(0.0, 0.0), p(1.0, 1.0), p(0.0, 1.0), p(1.0, 2.0)));
+/// ```
+pub fn line_intersection(
+    a1: Point<f64>,
+    a2: Point<f64>,
+    b1: Point<f64>,
+    b2: Point<f64>,
+) -> Option<Point<f64>> {
+    let d = (a2 - a1).cross(b2 - b1);
+    if d == 0.0 {
+        return None;
+    }
+    let t = (b1 - a1).cross(b2 - b1) / d;
+    Some(a1 + (a2 - a1) * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    #[test]
+    fn test_segments_intersect() {
+        let mut rng = XorShift::new();
+        let mut gen = || Point::new((rng.next_u32() % 7) as i64, (rng.next_u32() % 7) as i64);
+        for _ in 0..2000 {
+            let (a1, a2, b1, b2) = (gen(), gen(), gen(), gen());
+            // a common point, if any, is an endpoint or a1 + (a2 - a1) * k / d for an integer k,
+            // so check the points on the grid scaled by d
+            let d = std::cmp::max((a2 - a1).cross(b2 - b1).abs(), 1);
+            let (sa1, sb1, sb2) = (a1 * d, b1 * d, b2 * d);
+            let expected = (0..=d)
+                .map(|k| sa1 + (a2 - a1) * k)
+                .chain(vec![sb1, sb2])
+                .any(|p| p.on_segment(sa1, a2 * d) && p.on_segment(sb1, sb2));
+            assert_eq!(expected, segments_intersect(a1, a2, b1, b2));
+            assert_eq!(
+                segments_intersect(a1, a2, b1, b2),
+                segments_intersect(b2, b1, a1, a2)
+            );
+        }
+        let p = |x: i64, y: i64| Point::new(x, y);
+        assert!(segments_intersect(p(0, 0), p(4, 0), p(2, 0), p(6, 0)));
+        assert!(!segments_intersect(p(0, 0), p(1, 0), p(2, 0), p(6, 0)));
+        assert!(segments_intersect(p(1, 1), p(1, 1), p(0, 0), p(2, 2)));
+        assert!(!segments_intersect(p(0, 0), p(2, 1), p(1, 1), p(3, 2)));
+    }
+
+    #[test]
+    fn test_cmp_arg() {
+        let mut v: Vec<Point<i64>> = vec![];
+        for x in -2..=2 {
+            for y in -2..=2 {
+                if (x, y) != (0, 0) {
+                    v.push(Point::new(x, y));
+                }
+            }
+        }
+        let mut sorted = v.clone();
+        sorted.sort_by(|&a, &b| a.cmp_arg(b));
+        for w in sorted.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let (fa, fb) = (
+                Point::new(a.x as f64, a.y as f64).arg(),
+                Point::new(b.x as f64, b.y as f64).arg(),
+            );
+            assert!(fa <= fb + 1e-12);
+            assert_eq!(
+                a.cross(b) == 0 && a.dot(b) > 0,
+                a.cmp_arg(b) == cmp::Ordering::Equal
+            );
+        }
+        assert_eq!(Point::new(-1, 0), sorted[sorted.len() - 1]);
+    }
+
+    #[test]
+    fn test_float() {
+        let a = Point::new(3.0, 4.0);
+        assert_eq!(5.0, a.abs());
+        let b = a.rotate(std::f64::consts::FRAC_PI_2);
+        assert!(b.dist(Point::new(-4.0, 3.0)) < 1e-9);
+        assert!((b.arg() - a.arg() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,355 @@
+//! Polygons and convex hulls
+use super::point::{Coord, Orientation, Point};
+use std::cmp;
+
+/// Location of a point relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    OnBoundary,
+    Outside,
+}
+
+/// Returns twice the signed area of the polygon, which is positive if the vertices are in counterclockwise order.
+///
+/// The result is exact for integer coordinates.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{area2, Point};
+/// let poly = vec![Point::new(0i64, 0), Point::new(4, 0), Point::new(4, 3), Point::new(0, 3)];
+/// assert_eq!(24, area2(&poly));
+/// ```
+pub fn area2<T: Coord>(poly: &[Point<T>]) -> T {
+    let n = poly.len();
+    (0..n).fold(T::zero(), |acc, i| acc + poly[i].cross(poly[(i + 1) % n]))
+}
+
+/// Returns the location of `p` relative to a simple polygon.
+///
+/// O(n) by the winding number.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{polygon_contains, Location, Point};
+/// let poly = vec![Point::new(0i64, 0), Point::new(4, 0), Point::new(0, 4)];
+/// assert_eq!(Location::Inside, polygon_contains(&poly, Point::new(1, 1)));
+/// assert_eq!(Location::OnBoundary, polygon_contains(&poly, Point::new(2, 2)));
+/// assert_eq!(Location::Outside, polygon_contains(&poly, Point::new(3, 3)));
+/// ```
+pub fn polygon_contains<T: Coord>(poly: &[Point<T>], p: Point<T>) -> Location {
+    let n = poly.len();
+    let mut winding = 0;
+    for i in 0..n {
+        let (a, b) = (poly[i], poly[(i + 1) % n]);
+        if p.on_segment(a, b) {
+            return Location::OnBoundary;
+        }
+        // count edges crossing the ray from p to the right
+        if a.y <= p.y {
+            if b.y > p.y && a.orientation(b, p) == Orientation::CounterClockwise {
+                winding += 1;
+            }
+        } else if b.y <= p.y && a.orientation(b, p) == Orientation::Clockwise {
+            winding -= 1;
+        }
+    }
+    if winding != 0 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+fn sort_points<T: Coord>(points: &mut [Point<T>]) {
+    points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+}
+
+/// Returns the convex hull in counterclockwise order starting from the lexicographically smallest point.
+///
+/// Collinear points on edges are excluded.
+///
+/// O(n log n) by Andrew's monotone chain.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{convex_hull, Point};
+/// let p = |x: i64, y: i64| Point::new(x, y);
+/// let points = vec![p(0, 0), p(2, 0), p(1, 1), p(2, 2), p(0, 2), p(1, 0)];
+/// assert_eq!(vec![p(0, 0), p(2, 0), p(2, 2), p(0, 2)], convex_hull(&points));
+/// ```
+pub fn convex_hull<T: Coord>(points: &[Point<T>]) -> Vec<Point<T>> {
+    let mut points = points.to_vec();
+    sort_points(&mut points);
+    points.dedup();
+    if points.len() <= 2 {
+        return points;
+    }
+    let mut hull: Vec<Point<T>> = Vec::with_capacity(points.len() + 1);
+    let turns_left = |hull: &[Point<T>], p: Point<T>| {
+        hull[hull.len() - 2].orientation(hull[hull.len() - 1], p) == Orientation::CounterClockwise
+    };
+    // lower hull from left to right
+    for &p in &points {
+        while hull.len() >= 2 && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    // upper hull from right to left
+    let lower_len = hull.len();
+    for &p in points.iter().rev().skip(1) {
+        while hull.len() > lower_len && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull.pop();
+    hull
+}
+
+/// Returns the farthest pair of points, or `None` if `points` is empty.
+///
+/// O(n log n) by rotating calipers over the convex hull.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{diameter, Point};
+/// let p = |x: i64, y: i64| Point::new(x, y);
+/// let points = vec![p(0, 0), p(3, 1), p(1, 1), p(1, 4), p(2, 2)];
+/// let (a, b) = diameter(&points).unwrap();
+/// assert_eq!(17, a.dist2(b));
+/// ```
+pub fn diameter<T: Coord>(points: &[Point<T>]) -> Option<(Point<T>, Point<T>)> {
+    let hull = convex_hull(points);
+    let n = hull.len();
+    match n {
+        0 => return None,
+        1 => return Some((hull[0], hull[0])),
+        2 => return Some((hull[0], hull[1])),
+        _ => {}
+    }
+    let mut best = (hull[0], hull[1]);
+    let mut j = 1;
+    for i in 0..n {
+        let edge = hull[(i + 1) % n] - hull[i];
+        // advance j while the next vertex is farther from the edge i -> i + 1
+        while edge.cross(hull[(j + 1) % n] - hull[j]) > T::zero() {
+            j = (j + 1) % n;
+        }
+        for &k in &[i, (i + 1) % n] {
+            if hull[k].dist2(hull[j]) > best.0.dist2(best.1) {
+                best = (hull[k], hull[j]);
+            }
+        }
+    }
+    Some(best)
+}
+
+/// Returns the indices of the closest pair of points, or `None` if there are less than two points.
+///
+/// O(n log n) by divide and conquer.
+///
+/// # Examples
+/// ```
+/// use algorithms::geometry::{closest_pair, Point};
+/// let p = |x: i64, y: i64| Point::new(x, y);
+/// let points = vec![p(0, 0), p(5, 5), p(3, 1), p(9, 0), p(4, 2)];
+/// assert_eq!(Some((2, 4)), closest_pair(&points));
+/// ```
+pub fn closest_pair<T: Coord>(points: &[Point<T>]) -> Option<(usize, usize)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut idx: Vec<usize> = (0..points.len()).collect();
+    idx.sort_by(|&i, &j| points[i].partial_cmp(&points[j]).unwrap());
+    let mut best = (idx[0], idx[1]);
+    let mut best_d = points[idx[0]].dist2(points[idx[1]]);
+    closest_pair_rec(points, &mut idx, &mut best, &mut best_d);
+    Some((cmp::min(best.0, best.1), cmp::max(best.0, best.1)))
+}
+
+/// `idx` is sorted by x, and is sorted by y after this call.
+fn closest_pair_rec<T: Coord>(
+    points: &[Point<T>],
+    idx: &mut [usize],
+    best: &mut (usize, usize),
+    best_d: &mut T,
+) {
+    let n = idx.len();
+    if n <= 1 {
+        return;
+    }
+    let mid = n / 2;
+    let mid_x = points[idx[mid]].x;
+    {
+        let (left, right) = idx.split_at_mut(mid);
+        closest_pair_rec(points, left, best, best_d);
+        closest_pair_rec(points, right, best, best_d);
+    }
+    // merge two halves sorted by y
+    let mut merged = Vec::with_capacity(n);
+    let (mut l, mut r) = (0, mid);
+    while l < mid || r < n {
+        if r == n || (l < mid && points[idx[l]].y <= points[idx[r]].y) {
+            merged.push(idx[l]);
+            l += 1;
+        } else {
+            merged.push(idx[r]);
+            r += 1;
+        }
+    }
+    idx.copy_from_slice(&merged);
+    // check points in the strip around mid_x in the order of y
+    let mut strip: Vec<usize> = Vec::new();
+    for &i in idx.iter() {
+        let dx = points[i].x - mid_x;
+        if dx * dx >= *best_d {
+            continue;
+        }
+        for &j in strip.iter().rev() {
+            let dy = points[i].y - points[j].y;
+            if dy * dy >= *best_d {
+                break;
+            }
+            let d = points[i].dist2(points[j]);
+            if d < *best_d {
+                *best_d = d;
+                *best = (i, j);
+            }
+        }
+        strip.push(i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    fn random_points(rng: &mut XorShift, n: usize, range: u32) -> Vec<Point<i64>> {
+        (0..n)
+            .map(|_| {
+                Point::new(
+                    (rng.next_u32() % range) as i64,
+                    (rng.next_u32() % range) as i64,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let mut rng = XorShift::new();
+        for n in 0..60 {
+            let points = random_points(&mut rng, n, 10);
+            let hull = convex_hull(&points);
+            let m = hull.len();
+            if m >= 3 {
+                for i in 0..m {
+                    let (a, b) = (hull[i], hull[(i + 1) % m]);
+                    for &p in &points {
+                        assert_ne!(Orientation::Clockwise, a.orientation(b, p));
+                    }
+                    assert_eq!(
+                        Orientation::CounterClockwise,
+                        a.orientation(b, hull[(i + 2) % m])
+                    );
+                }
+                let a = area2(&hull);
+                assert!(a > 0);
+                for &p in &points {
+                    assert_ne!(Location::Outside, polygon_contains(&hull, p));
+                }
+            }
+            // diameter is attained on the hull
+            if let Some((a, b)) = diameter(&points) {
+                let expected = points
+                    .iter()
+                    .flat_map(|&p| points.iter().map(move |&q| p.dist2(q)))
+                    .max()
+                    .unwrap();
+                assert_eq!(expected, a.dist2(b));
+            }
+        }
+        let collinear = vec![Point::new(0, 0), Point::new(2, 2), Point::new(1, 1)];
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(2, 2)],
+            convex_hull(&collinear)
+        );
+        assert_eq!(8, {
+            let (a, b) = diameter(&collinear).unwrap();
+            a.dist2(b)
+        });
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        // concave polygon shaped like "U"
+        let poly: Vec<Point<i64>> = vec![
+            (0, 0),
+            (6, 0),
+            (6, 6),
+            (4, 6),
+            (4, 2),
+            (2, 2),
+            (2, 6),
+            (0, 6),
+        ]
+        .into_iter()
+        .map(Point::from)
+        .collect();
+        assert_eq!(2 * 28, area2(&poly));
+        let rev: Vec<_> = poly.iter().rev().cloned().collect();
+        assert_eq!(-2 * 28, area2(&rev));
+        let inside = |x: i64, y: i64| {
+            (0 < x && x < 6 && 0 < y && y < 2)
+                || (0 < x && x < 2 && 0 < y && y < 6)
+                || (4 < x && x < 6 && 0 < y && y < 6)
+        };
+        for x in -1..=7 {
+            for y in -1..=7 {
+                let p = Point::new(x, y);
+                let on_boundary =
+                    (0..poly.len()).any(|i| p.on_segment(poly[i], poly[(i + 1) % poly.len()]));
+                let expected = if on_boundary {
+                    Location::OnBoundary
+                } else if inside(x, y) {
+                    Location::Inside
+                } else {
+                    Location::Outside
+                };
+                assert_eq!(expected, polygon_contains(&poly, p));
+                assert_eq!(expected, polygon_contains(&rev, p));
+            }
+        }
+    }
+
+    #[test]
+    fn test_closest_pair() {
+        let mut rng = XorShift::new();
+        for n in 0..100 {
+            let points = random_points(&mut rng, n, 1000);
+            let mut expected = None;
+            for i in 0..n {
+                for j in i + 1..n {
+                    let d = points[i].dist2(points[j]);
+                    if expected.map_or(true, |e| d < e) {
+                        expected = Some(d);
+                    }
+                }
+            }
+            let found = closest_pair(&points).map(|(i, j)| {
+                assert!(i < j);
+                points[i].dist2(points[j])
+            });
+            assert_eq!(expected, found);
+        }
+        let float = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.5, 0.0),
+            Point::new(0.5, 0.5),
+        ];
+        assert_eq!(Some((0, 2)), closest_pair(&float));
+    }
+}
@@ -0,0 +1,253 @@
+//! Eulerian path / circuit by Hierholzer's algorithm
+//!
+//! Graphs are given as a number of vertices and a list of edges `(from, to)`.
+//! Multiple edges and self-loops are allowed.
+//! Edges are identified by indices in the list.
+
+/// Reason why an Eulerian path doesn't exist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerError {
+    /// Degrees of the vertex violate the condition.
+    ///
+    /// For directed graphs, either `|out-degree - in-degree| > 1` holds for the vertex
+    /// or there is another vertex with the same imbalance.
+    /// For undirected graphs, the vertex is the third vertex of odd degree.
+    DegreeImbalance(usize),
+    /// The edge is not reachable from other edges.
+    Disconnected(usize),
+}
+
+/// Eulerian path returned by [`euler_path_directed`](fn.euler_path_directed.html)
+/// and [`euler_path_undirected`](fn.euler_path_undirected.html).
+///
+/// The path visits `vertices[0], vertices[1], ...` in order and
+/// `edges[i]` connects `vertices[i]` and `vertices[i + 1]`.
+/// It is a circuit if the first vertex and the last vertex are the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EulerPath {
+    pub vertices: Vec<usize>,
+    pub edges: Vec<usize>,
+}
+
+impl EulerPath {
+    /// Returns `true` if the path ends at the starting vertex.
+    pub fn is_circuit(&self) -> bool {
+        self.vertices.first() == self.vertices.last()
+    }
+}
+
+/// Returns an Eulerian path of a directed graph which uses every edge exactly once.
+/// A circuit is returned whenever it exists.
+///
+/// O(V + E). This is implemented without recursion.
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::{euler_path_directed, EulerError};
+/// let path = euler_path_directed(3, &[(0, 1), (1, 2), (1, 0)]).unwrap();
+/// assert_eq!(vec![1, 0, 1, 2], path.vertices);
+/// assert_eq!(vec![2, 0, 1], path.edges);
+///
+/// let res = euler_path_directed(3, &[(0, 1), (0, 2)]);
+/// assert_eq!(Err(EulerError::DegreeImbalance(0)), res);
+/// ```
+pub fn euler_path_directed(n: usize, edges: &[(usize, usize)]) -> Result<EulerPath, EulerError> {
+    let mut diff = vec![0i64; n];
+    for &(from, to) in edges {
+        diff[from] += 1;
+        diff[to] -= 1;
+    }
+    let mut start = None;
+    let mut end = None;
+    for (v, &d) in diff.iter().enumerate() {
+        match d {
+            0 => {}
+            1 if start.is_none() => start = Some(v),
+            -1 if end.is_none() => end = Some(v),
+            _ => return Err(EulerError::DegreeImbalance(v)),
+        }
+    }
+    let start = start.or_else(|| edges.first().map(|&(from, _)| from));
+    hierholzer(n, edges, start, true)
+}
+
+/// Returns an Eulerian path of an undirected graph which uses every edge exactly once.
+/// A circuit is returned whenever it exists.
+///
+/// O(V + E). This is implemented without recursion.
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::{euler_path_undirected, EulerError};
+/// let path = euler_path_undirected(3, &[(0, 1), (1, 2), (1, 0)]).unwrap();
+/// assert_eq!(vec![1, 0, 1, 2], path.vertices);
+/// assert_eq!(vec![0, 2, 1], path.edges);
+///
+/// let res = euler_path_undirected(4, &[(0, 1), (2, 3)]);
+/// assert_eq!(Err(EulerError::DegreeImbalance(2)), res);
+/// let res = euler_path_undirected(4, &[(0, 1), (1, 0), (2, 3), (3, 2)]);
+/// assert_eq!(Err(EulerError::Disconnected(2)), res);
+/// ```
+pub fn euler_path_undirected(n: usize, edges: &[(usize, usize)]) -> Result<EulerPath, EulerError> {
+    let mut odd = vec![false; n];
+    for &(a, b) in edges {
+        odd[a] = !odd[a];
+        odd[b] = !odd[b];
+    }
+    let mut start = None;
+    let mut num_odd = 0;
+    for (v, &o) in odd.iter().enumerate() {
+        if o {
+            num_odd += 1;
+            if num_odd > 2 {
+                return Err(EulerError::DegreeImbalance(v));
+            }
+            start = start.or(Some(v));
+        }
+    }
+    let start = start.or_else(|| edges.first().map(|&(a, _)| a));
+    hierholzer(n, edges, start, false)
+}
+
+fn hierholzer(
+    n: usize,
+    edges: &[(usize, usize)],
+    start: Option<usize>,
+    directed: bool,
+) -> Result<EulerPath, EulerError> {
+    let start = match start {
+        Some(start) => start,
+        None => {
+            return Ok(EulerPath {
+                vertices: (0..n.min(1)).collect(),
+                edges: Vec::new(),
+            })
+        }
+    };
+
+    // adjacency list in the compressed sparse row format.
+    // edge ids of vertex `v` are stored in `adj[offset[v]..offset[v + 1]]`.
+    let mut offset = vec![0; n + 1];
+    for &(a, b) in edges {
+        offset[a + 1] += 1;
+        if !directed {
+            offset[b + 1] += 1;
+        }
+    }
+    for v in 0..n {
+        offset[v + 1] += offset[v];
+    }
+    let mut pos = offset.clone();
+    let mut adj = vec![0; offset[n]];
+    for (id, &(a, b)) in edges.iter().enumerate() {
+        adj[pos[a]] = id;
+        pos[a] += 1;
+        if !directed {
+            adj[pos[b]] = id;
+            pos[b] += 1;
+        }
+    }
+
+    // `adj[next[v]]` is the next edge of `v` to check.
+    let mut next = offset.clone();
+    let mut used = vec![false; edges.len()];
+    let mut path_vertices = Vec::with_capacity(edges.len() + 1);
+    let mut path_edges = Vec::with_capacity(edges.len());
+    // stack of (vertex, edge used to reach the vertex)
+    let mut stack = vec![(start, usize::max_value())];
+    while let Some(&(v, e)) = stack.last() {
+        while next[v] < offset[v + 1] && used[adj[next[v]]] {
+            next[v] += 1;
+        }
+        if next[v] < offset[v + 1] {
+            let id = adj[next[v]];
+            used[id] = true;
+            let (a, b) = edges[id];
+            stack.push((if a == v { b } else { a }, id));
+        } else {
+            stack.pop();
+            path_vertices.push(v);
+            if e != usize::max_value() {
+                path_edges.push(e);
+            }
+        }
+    }
+
+    if let Some(id) = used.iter().position(|&u| !u) {
+        return Err(EulerError::Disconnected(id));
+    }
+    path_vertices.reverse();
+    path_edges.reverse();
+    Ok(EulerPath {
+        vertices: path_vertices,
+        edges: path_edges,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(n: usize, edges: &[(usize, usize)], path: &EulerPath, directed: bool) {
+        assert_eq!(edges.len(), path.edges.len());
+        assert_eq!(edges.len() + 1, path.vertices.len());
+        let mut seen = vec![false; edges.len()];
+        for (i, &id) in path.edges.iter().enumerate() {
+            assert!(!seen[id]);
+            seen[id] = true;
+            let (a, b) = edges[id];
+            let (x, y) = (path.vertices[i], path.vertices[i + 1]);
+            assert!(x < n && y < n);
+            assert!((a, b) == (x, y) || (!directed && (a, b) == (y, x)));
+        }
+    }
+
+    #[test]
+    fn test_directed_circuit() {
+        let edges = [(0, 1), (1, 2), (2, 0), (1, 3), (3, 1), (2, 2)];
+        let path = euler_path_directed(4, &edges).unwrap();
+        check(4, &edges, &path, true);
+        assert!(path.is_circuit());
+    }
+
+    #[test]
+    fn test_directed_error() {
+        assert_eq!(
+            Err(EulerError::DegreeImbalance(1)),
+            euler_path_directed(3, &[(0, 1), (2, 1)])
+        );
+        assert_eq!(
+            Err(EulerError::Disconnected(2)),
+            euler_path_directed(4, &[(0, 1), (1, 0), (2, 3), (3, 2)])
+        );
+    }
+
+    #[test]
+    fn test_undirected_path() {
+        let edges = [(0, 1), (1, 2), (2, 0), (0, 3), (3, 3), (1, 0)];
+        let path = euler_path_undirected(4, &edges).unwrap();
+        check(4, &edges, &path, false);
+        assert!(!path.is_circuit());
+        assert_eq!(1, path.vertices[0]);
+        assert_eq!(3, path.vertices[6]);
+    }
+
+    #[test]
+    fn test_no_edges() {
+        let path = euler_path_undirected(3, &[]).unwrap();
+        assert_eq!(vec![0], path.vertices);
+        assert!(path.edges.is_empty());
+        let path = euler_path_directed(0, &[]).unwrap();
+        assert!(path.vertices.is_empty());
+    }
+
+    #[test]
+    fn test_large() {
+        let n = 1000;
+        let edges: Vec<(usize, usize)> = (0..1_000_000).map(|i| (i % n, (i + 1) % n)).collect();
+        let path = euler_path_directed(n, &edges).unwrap();
+        check(n, &edges, &path, true);
+        let path = euler_path_undirected(n, &edges).unwrap();
+        check(n, &edges, &path, false);
+    }
+}
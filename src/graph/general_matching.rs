@@ -0,0 +1,235 @@
+// ref: https://cp-algorithms.com/graph/Edmonds_Blossom.html
+
+const NONE: usize = std::usize::MAX;
+
+/// Maximum matching in general undirected graphs by Edmonds' blossom algorithm
+/// O(V^3)
+///
+/// Use [`BipartiteMatching`](struct.BipartiteMatching.html) if the graph is bipartite.
+///
+/// # Examples
+///
+/// ```
+/// use algorithms::graph::GeneralMatching;
+/// // odd cycle 0 - 1 - 2 - 0 with an extra vertex 3 connected to 2
+/// let mut gm = GeneralMatching::new(4);
+/// gm.add_edge(0, 1);
+/// gm.add_edge(1, 2);
+/// gm.add_edge(2, 0);
+/// gm.add_edge(2, 3);
+/// let mate = gm.compute();
+/// assert_eq!(Some(3), mate[2]);
+/// assert_eq!(Some(2), mate[3]);
+/// assert_eq!(Some(1), mate[0]);
+/// assert_eq!(Some(0), mate[1]);
+/// ```
+pub struct GeneralMatching {
+    n: usize,
+    graph: Vec<Vec<usize>>,
+}
+
+impl GeneralMatching {
+    pub fn new(n: usize) -> Self {
+        GeneralMatching {
+            n,
+            graph: vec![Vec::new(); n],
+        }
+    }
+
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        assert!(u < self.n);
+        assert!(v < self.n);
+        self.graph[u].push(v);
+        self.graph[v].push(u);
+    }
+
+    /// Returns `mate` where `mate[v]` is the vertex matched with `v`.
+    /// `mate[v]` is `None` if `v` is not matched.
+    pub fn compute(&self) -> Vec<Option<usize>> {
+        let mut state = State {
+            mate: vec![NONE; self.n],
+            parent: vec![NONE; self.n],
+            base: (0..self.n).collect(),
+            used: vec![false; self.n],
+            blossom: vec![false; self.n],
+        };
+        for root in 0..self.n {
+            if state.mate[root] != NONE {
+                continue;
+            }
+            if let Some(mut v) = self.find_path(root, &mut state) {
+                // flip edges along the augmenting path
+                while v != NONE {
+                    let pv = state.parent[v];
+                    let ppv = state.mate[pv];
+                    state.mate[v] = pv;
+                    state.mate[pv] = v;
+                    v = ppv;
+                }
+            }
+        }
+        state
+            .mate
+            .into_iter()
+            .map(|m| if m != NONE { Some(m) } else { None })
+            .collect()
+    }
+
+    /// Returns the end of an augmenting path from `root` if it exists.
+    fn find_path(&self, root: usize, st: &mut State) -> Option<usize> {
+        for v in 0..self.n {
+            st.used[v] = false;
+            st.parent[v] = NONE;
+            st.base[v] = v;
+        }
+        st.used[root] = true;
+        let mut queue = vec![root];
+        let mut head = 0;
+        while head < queue.len() {
+            let v = queue[head];
+            head += 1;
+            for &to in &self.graph[v] {
+                if st.base[v] == st.base[to] || st.mate[v] == to {
+                    continue;
+                }
+                if to == root || (st.mate[to] != NONE && st.parent[st.mate[to]] != NONE) {
+                    // found an odd cycle. contract it into a blossom.
+                    let cur_base = st.lca(v, to);
+                    for b in st.blossom.iter_mut() {
+                        *b = false;
+                    }
+                    st.mark_path(v, cur_base, to);
+                    st.mark_path(to, cur_base, v);
+                    for i in 0..self.n {
+                        if st.blossom[st.base[i]] {
+                            st.base[i] = cur_base;
+                            if !st.used[i] {
+                                st.used[i] = true;
+                                queue.push(i);
+                            }
+                        }
+                    }
+                } else if st.parent[to] == NONE {
+                    st.parent[to] = v;
+                    if st.mate[to] == NONE {
+                        return Some(to);
+                    }
+                    let next = st.mate[to];
+                    st.used[next] = true;
+                    queue.push(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+struct State {
+    mate: Vec<usize>,   // mate[v] stores the vertex matched with v
+    parent: Vec<usize>, // parent[v] stores the previous vertex in the alternating tree
+    base: Vec<usize>,   // base[v] stores the base of the blossom containing v
+    used: Vec<bool>,    // used[v] is true if v is an even vertex in the alternating tree
+    blossom: Vec<bool>, // blossom[b] is true if base b is in the current blossom
+}
+
+impl State {
+    fn lca(&self, mut a: usize, mut b: usize) -> usize {
+        let mut visited = vec![false; self.mate.len()];
+        loop {
+            a = self.base[a];
+            visited[a] = true;
+            if self.mate[a] == NONE {
+                break;
+            }
+            a = self.parent[self.mate[a]];
+        }
+        loop {
+            b = self.base[b];
+            if visited[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b]];
+        }
+    }
+
+    fn mark_path(&mut self, mut v: usize, b: usize, mut child: usize) {
+        while self.base[v] != b {
+            self.blossom[self.base[v]] = true;
+            self.blossom[self.base[self.mate[v]]] = true;
+            self.parent[v] = child;
+            child = self.mate[v];
+            v = self.parent[self.mate[v]];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    fn brute_force(n: usize, edges: &[(usize, usize)]) -> usize {
+        let mut best = 0;
+        for mask in 0..(1u32 << edges.len()) {
+            let mut used = vec![false; n];
+            let mut ok = true;
+            for (i, &(u, v)) in edges.iter().enumerate() {
+                if mask >> i & 1 == 1 {
+                    if used[u] || used[v] || u == v {
+                        ok = false;
+                        break;
+                    }
+                    used[u] = true;
+                    used[v] = true;
+                }
+            }
+            if ok {
+                best = best.max(mask.count_ones() as usize);
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = XorShift::new();
+        for _ in 0..200 {
+            let n = (rng.next_u32() % 8 + 1) as usize;
+            let m = (rng.next_u32() % 12) as usize;
+            let edges: Vec<(usize, usize)> = (0..m)
+                .map(|_| {
+                    let u = (rng.next_u32() as usize) % n;
+                    let v = (rng.next_u32() as usize) % n;
+                    (u, v)
+                })
+                .collect();
+            let mut gm = GeneralMatching::new(n);
+            for &(u, v) in &edges {
+                gm.add_edge(u, v);
+            }
+            let mate = gm.compute();
+            let mut size = 0;
+            for v in 0..n {
+                if let Some(u) = mate[v] {
+                    assert_eq!(Some(v), mate[u]);
+                    assert!(edges.contains(&(u, v)) || edges.contains(&(v, u)));
+                    assert_ne!(u, v);
+                    size += 1;
+                }
+            }
+            assert_eq!(brute_force(n, &edges), size / 2);
+        }
+    }
+
+    #[test]
+    fn test_petersen() {
+        let mut gm = GeneralMatching::new(10);
+        for i in 0..5 {
+            gm.add_edge(i, (i + 1) % 5);
+            gm.add_edge(i, i + 5);
+            gm.add_edge(i + 5, (i + 2) % 5 + 5);
+        }
+        let mate = gm.compute();
+        assert!(mate.iter().all(|m| m.is_some()));
+    }
+}
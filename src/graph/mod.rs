@@ -1,3 +1,18 @@
+//! graph algorithms
+//!
+//! * [`BipartiteMatching`](struct.BipartiteMatching.html) - maximum matching in bipartite graphs
+//! * [`euler_path_directed`](fn.euler_path_directed.html) - Eulerian path / circuit by Hierholzer's algorithm
+//! * [`GeneralMatching`](struct.GeneralMatching.html) - maximum matching in general graphs
+//! * [`topological_sort`](fn.topological_sort.html) - topological sort, cycle detection and DP on DAGs
+//!
 pub mod bipartite_matching;
+pub mod euler_tour;
+pub mod general_matching;
+pub mod topological_sort;
 
 pub use self::bipartite_matching::BipartiteMatching;
+pub use self::euler_tour::{euler_path_directed, euler_path_undirected, EulerError, EulerPath};
+pub use self::general_matching::GeneralMatching;
+pub use self::topological_sort::{
+    count_paths, find_cycle, longest_path, topological_sort, topological_sort_lexicographic,
+};
@@ -0,0 +1,265 @@
+//! Topological sort and dynamic programming on directed acyclic graphs
+//!
+//! Every function takes a graph as adjacency lists, i.e. `graph[v]` stores
+//! the list of vertices `u` such that there is an edge `v -> u`.
+//! Multiple edges are allowed.
+use crate::math::{Mint, Module};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+fn in_degrees(graph: &[Vec<usize>]) -> Vec<usize> {
+    let mut deg = vec![0; graph.len()];
+    for edges in graph {
+        for &to in edges {
+            deg[to] += 1;
+        }
+    }
+    deg
+}
+
+/// Returns a topological order of vertices by Kahn's algorithm.
+/// Returns `None` if the graph contains a cycle.
+///
+/// O(V + E)
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::topological_sort;
+/// let graph = vec![vec![1, 2], vec![2], vec![]];
+/// assert_eq!(Some(vec![0, 1, 2]), topological_sort(&graph));
+///
+/// let graph = vec![vec![1], vec![0]];
+/// assert_eq!(None, topological_sort(&graph));
+/// ```
+pub fn topological_sort(graph: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut deg = in_degrees(graph);
+    let mut order: Vec<usize> = (0..graph.len()).filter(|&v| deg[v] == 0).collect();
+    // order[..head] is already processed and order[head..] is the queue
+    let mut head = 0;
+    while head < order.len() {
+        let v = order[head];
+        head += 1;
+        for &to in &graph[v] {
+            deg[to] -= 1;
+            if deg[to] == 0 {
+                order.push(to);
+            }
+        }
+    }
+    if order.len() == graph.len() {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// Returns the lexicographically smallest topological order of vertices.
+/// Returns `None` if the graph contains a cycle.
+///
+/// O((V + E) log V)
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::topological_sort_lexicographic;
+/// let graph = vec![vec![], vec![], vec![0], vec![1]];
+/// assert_eq!(Some(vec![2, 0, 3, 1]), topological_sort_lexicographic(&graph));
+/// ```
+pub fn topological_sort_lexicographic(graph: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let mut deg = in_degrees(graph);
+    let mut heap: BinaryHeap<Reverse<usize>> = (0..graph.len())
+        .filter(|&v| deg[v] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = Vec::with_capacity(graph.len());
+    while let Some(Reverse(v)) = heap.pop() {
+        order.push(v);
+        for &to in &graph[v] {
+            deg[to] -= 1;
+            if deg[to] == 0 {
+                heap.push(Reverse(to));
+            }
+        }
+    }
+    if order.len() == graph.len() {
+        Some(order)
+    } else {
+        None
+    }
+}
+
+/// Returns vertices of a directed cycle `[v_0, v_1, ..., v_{k-1}]`
+/// such that there are edges `v_0 -> v_1 -> ... -> v_{k-1} -> v_0`.
+/// Returns `None` if the graph is acyclic.
+///
+/// A self-loop `v -> v` is reported as `[v]`.
+///
+/// O(V + E). This is implemented without recursion.
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::find_cycle;
+/// let graph = vec![vec![1], vec![2], vec![3], vec![1]];
+/// assert_eq!(Some(vec![1, 2, 3]), find_cycle(&graph));
+///
+/// let graph = vec![vec![1, 2], vec![2], vec![]];
+/// assert_eq!(None, find_cycle(&graph));
+/// ```
+pub fn find_cycle(graph: &[Vec<usize>]) -> Option<Vec<usize>> {
+    const UNVISITED: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const DONE: u8 = 2;
+
+    let n = graph.len();
+    let mut state = vec![UNVISITED; n];
+    // stack of (vertex, index of the next edge to visit)
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    for root in 0..n {
+        if state[root] != UNVISITED {
+            continue;
+        }
+        state[root] = ON_STACK;
+        stack.push((root, 0));
+        while let Some(&(v, i)) = stack.last() {
+            if let Some(&to) = graph[v].get(i) {
+                stack.last_mut().unwrap().1 += 1;
+                if state[to] == UNVISITED {
+                    state[to] = ON_STACK;
+                    stack.push((to, 0));
+                } else if state[to] == ON_STACK {
+                    let start = stack.iter().position(|&(u, _)| u == to).unwrap();
+                    return Some(stack[start..].iter().map(|&(u, _)| u).collect());
+                }
+            } else {
+                state[v] = DONE;
+                stack.pop();
+            }
+        }
+    }
+    None
+}
+
+/// Returns `dist` where `dist[v]` is the number of edges of the longest path ending at `v`.
+/// Returns `None` if the graph contains a cycle.
+///
+/// O(V + E)
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::longest_path;
+/// let graph = vec![vec![1, 3], vec![2], vec![3], vec![]];
+/// assert_eq!(Some(vec![0, 1, 2, 3]), longest_path(&graph));
+/// ```
+pub fn longest_path(graph: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let order = topological_sort(graph)?;
+    let mut dist = vec![0; graph.len()];
+    for v in order {
+        for &to in &graph[v] {
+            if dist[to] < dist[v] + 1 {
+                dist[to] = dist[v] + 1;
+            }
+        }
+    }
+    Some(dist)
+}
+
+/// Returns `count` where `count[v]` is the number of paths from `source` to `v` modulo `M`.
+/// Multiple edges are counted as different paths.
+/// Returns `None` if the graph contains a cycle.
+///
+/// O(V + E)
+///
+/// # Examples
+/// ```
+/// use algorithms::graph::count_paths;
+/// use algorithms::math::{Mint107, Mod107};
+/// let graph = vec![vec![1, 2], vec![3], vec![3], vec![]];
+/// let count = count_paths::<Mod107>(&graph, 0).unwrap();
+/// assert_eq!(Mint107::from(2), count[3]);
+/// assert_eq!(Mint107::from(1), count[0]);
+/// ```
+pub fn count_paths<M: Module>(graph: &[Vec<usize>], source: usize) -> Option<Vec<Mint<M>>> {
+    assert!(source < graph.len());
+    let order = topological_sort(graph)?;
+    let mut count: Vec<Mint<M>> = vec![Mint::from(0); graph.len()];
+    count[source] = Mint::from(1);
+    for v in order {
+        let c = count[v];
+        for &to in &graph[v] {
+            count[to] += c;
+        }
+    }
+    Some(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Mod107;
+
+    fn is_topological_order(graph: &[Vec<usize>], order: &[usize]) -> bool {
+        let mut pos = vec![usize::max_value(); graph.len()];
+        for (i, &v) in order.iter().enumerate() {
+            pos[v] = i;
+        }
+        pos.iter().all(|&p| p != usize::max_value())
+            && (0..graph.len()).all(|v| graph[v].iter().all(|&to| pos[v] < pos[to]))
+    }
+
+    fn is_cycle(graph: &[Vec<usize>], cycle: &[usize]) -> bool {
+        !cycle.is_empty()
+            && (0..cycle.len()).all(|i| graph[cycle[i]].contains(&cycle[(i + 1) % cycle.len()]))
+    }
+
+    #[test]
+    fn test_acyclic() {
+        let graph = vec![vec![3], vec![0, 3], vec![1], vec![], vec![2, 3]];
+        let order = topological_sort(&graph).unwrap();
+        assert!(is_topological_order(&graph, &order));
+        assert_eq!(
+            Some(vec![4, 2, 1, 0, 3]),
+            topological_sort_lexicographic(&graph)
+        );
+        assert_eq!(None, find_cycle(&graph));
+        assert_eq!(Some(vec![3, 2, 1, 4, 0]), longest_path(&graph));
+    }
+
+    #[test]
+    fn test_cyclic() {
+        let graph = vec![vec![1], vec![2, 4], vec![3], vec![1], vec![]];
+        assert_eq!(None, topological_sort(&graph));
+        assert_eq!(None, topological_sort_lexicographic(&graph));
+        assert_eq!(None, longest_path(&graph));
+        assert!(count_paths::<Mod107>(&graph, 0).is_none());
+        let cycle = find_cycle(&graph).unwrap();
+        assert!(is_cycle(&graph, &cycle));
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let graph = vec![vec![1], vec![1]];
+        assert_eq!(None, topological_sort(&graph));
+        assert_eq!(Some(vec![1]), find_cycle(&graph));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // 0 => 1 (two edges), 0 -> 2, 1 -> 3, 2 -> 3
+        let graph = vec![vec![1, 1, 2], vec![3], vec![3], vec![]];
+        let count = count_paths::<Mod107>(&graph, 0).unwrap();
+        let expected: Vec<Mint<Mod107>> = vec![1, 2, 1, 3].into_iter().map(Mint::from).collect();
+        assert_eq!(expected, count);
+        let count = count_paths::<Mod107>(&graph, 2).unwrap();
+        let expected: Vec<Mint<Mod107>> = vec![0, 0, 1, 1].into_iter().map(Mint::from).collect();
+        assert_eq!(expected, count);
+    }
+
+    #[test]
+    fn test_long_path() {
+        let n = 200_000;
+        let graph: Vec<Vec<usize>> = (0..n)
+            .map(|v| if v + 1 < n { vec![v + 1] } else { vec![0] })
+            .collect();
+        let cycle = find_cycle(&graph).unwrap();
+        assert_eq!(n, cycle.len());
+    }
+}
@@ -1,11 +1,15 @@
 //! This library provides a collection of classic algorithms,
 //! focusing on programming contests such as codeforces and atcoder.
 pub mod data_structure;
+pub mod geometry;
 pub mod graph;
 pub mod math;
+pub mod strings;
 pub mod util;
 
 pub use data_structure::*;
+pub use geometry::*;
 pub use graph::*;
 pub use math::*;
+pub use strings::*;
 pub use util::*;
@@ -0,0 +1,697 @@
+//! Arbitrary-precision integers
+use super::convolution::convolution_crt;
+use super::mint::{Mint, Module};
+use std::cmp;
+use std::error;
+use std::fmt;
+use std::ops;
+use std::str;
+
+/// Each limb stores 9 decimal digits.
+const BASE: u32 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+/// Multiplication uses NTT if both operands have more limbs than this.
+const NAIVE_THRESHOLD: usize = 64;
+
+/// Error returned when parsing a string to a big integer fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "invalid digit found in string".fmt(f)
+    }
+}
+
+impl error::Error for ParseBigIntError {}
+
+/// Arbitrary-precision unsigned integer.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{BigUint, Mint107};
+/// let mut fact = BigUint::from(1u32);
+/// for i in 1..=30u32 {
+///     fact = fact * BigUint::from(i);
+/// }
+/// assert_eq!("265252859812191058636308480000000", fact.to_string());
+/// assert_eq!((1..=30).map(Mint107::from).product::<Mint107>(), fact.to_mint());
+///
+/// let x: BigUint = "123456789012345678901234567890".parse().unwrap();
+/// let (q, r) = x.div_rem(&BigUint::from(1_000_000_007u64));
+/// assert_eq!(x, q * BigUint::from(1_000_000_007u64) + r);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    digits: Vec<u32>, // little endian in base 10^9 without leading zeros
+}
+
+impl BigUint {
+    fn from_digits(mut digits: Vec<u32>) -> BigUint {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        BigUint { digits }
+    }
+
+    pub fn zero() -> BigUint {
+        BigUint { digits: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Returns `self^e`.
+    pub fn pow(&self, mut e: u32) -> BigUint {
+        let mut res = BigUint::from(1u32);
+        let mut base = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                res = &res * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        res
+    }
+
+    /// Returns `self` modulo `M`.
+    pub fn to_mint<M: Module>(&self) -> Mint<M> {
+        self.digits
+            .iter()
+            .rev()
+            .fold(Mint::from(0), |acc, &d| acc * BASE + d)
+    }
+
+    /// Returns `(self / d, self % d)` for a small divisor `d > 0`.
+    ///
+    /// O(n)
+    pub fn div_rem_small(&self, d: u32) -> (BigUint, u32) {
+        assert!(d > 0, "division by zero");
+        let mut q = vec![0; self.digits.len()];
+        let mut r = 0u64;
+        for i in (0..self.digits.len()).rev() {
+            let cur = r * BASE as u64 + self.digits[i] as u64;
+            q[i] = (cur / d as u64) as u32;
+            r = cur % d as u64;
+        }
+        (BigUint::from_digits(q), r as u32)
+    }
+
+    /// Returns `(self / d, self % d)`.
+    ///
+    /// O(n * m) by long division, where `n` and `m` are the lengths of `self` and `d`.
+    pub fn div_rem(&self, d: &BigUint) -> (BigUint, BigUint) {
+        assert!(!d.is_zero(), "division by zero");
+        if self < d {
+            return (BigUint::zero(), self.clone());
+        }
+        if d.digits.len() == 1 {
+            let (q, r) = self.div_rem_small(d.digits[0]);
+            return (q, BigUint::from(r));
+        }
+        // Knuth's algorithm D: normalize so that the top limb of the divisor is at least BASE / 2
+        let f = BASE / (d.digits[d.digits.len() - 1] + 1);
+        let mut u = mul_small(&self.digits, f);
+        u.push(0);
+        let v = mul_small(&d.digits, f);
+        let (n, m) = (v.len(), u.len() - v.len());
+        let base = BASE as u64;
+        let (v1, v2) = (v[n - 1] as u64, v[n - 2] as u64);
+        let mut q = vec![0; m];
+        for j in (0..m).rev() {
+            let top = u[j + n] as u64 * base + u[j + n - 1] as u64;
+            let mut qhat = cmp::min(top / v1, base - 1);
+            let mut rhat = top - qhat * v1;
+            while rhat < base && qhat * v2 > rhat * base + u[j + n - 2] as u64 {
+                qhat -= 1;
+                rhat += v1;
+            }
+            // u[j..=j + n] -= qhat * v
+            let mut borrow = 0i64;
+            let mut carry = 0u64;
+            for i in 0..=n {
+                let p = if i < n {
+                    qhat * v[i] as u64 + carry
+                } else {
+                    carry
+                };
+                carry = p / base;
+                let t = u[j + i] as i64 - (p % base) as i64 - borrow;
+                if t < 0 {
+                    u[j + i] = (t + base as i64) as u32;
+                    borrow = 1;
+                } else {
+                    u[j + i] = t as u32;
+                    borrow = 0;
+                }
+            }
+            if borrow > 0 {
+                // qhat was one too large
+                qhat -= 1;
+                let mut c = 0;
+                for i in 0..=n {
+                    let t = u[j + i] + if i < n { v[i] } else { 0 } + c;
+                    if t >= BASE {
+                        u[j + i] = t - BASE;
+                        c = 1;
+                    } else {
+                        u[j + i] = t;
+                        c = 0;
+                    }
+                }
+            }
+            q[j] = qhat as u32;
+        }
+        u.truncate(n);
+        let (r, _) = BigUint::from_digits(u).div_rem_small(f);
+        (BigUint::from_digits(q), r)
+    }
+}
+
+fn mul_small(a: &[u32], f: u32) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for &x in a {
+        let t = x as u64 * f as u64 + carry;
+        res.push((t % BASE as u64) as u32);
+        carry = t / BASE as u64;
+    }
+    if carry > 0 {
+        res.push(carry as u32);
+    }
+    res
+}
+
+fn mul_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![0u32; a.len() + b.len()];
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in b.iter().enumerate() {
+                let t = res[i + j] as u64 + x as u64 * y as u64 + carry;
+                res[i + j] = (t % BASE as u64) as u32;
+                carry = t / BASE as u64;
+            }
+            res[i + b.len()] = carry as u32;
+        }
+    } else {
+        // each coefficient is less than 10^18 * min(n, m), which is exactly restored by CRT
+        let c = convolution_crt(a, b);
+        let mut carry = 0u128;
+        for (r, x) in res.iter_mut().zip(c) {
+            let t = x + carry;
+            *r = (t % BASE as u128) as u32;
+            carry = t / BASE as u128;
+        }
+        res[a.len() + b.len() - 1] = carry as u32;
+    }
+    res
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> cmp::Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl<'a> ops::Add<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let n = cmp::max(self.digits.len(), other.digits.len());
+        let mut res = Vec::with_capacity(n + 1);
+        let mut carry = 0;
+        for i in 0..n {
+            let t = self.digits.get(i).unwrap_or(&0) + other.digits.get(i).unwrap_or(&0) + carry;
+            if t >= BASE {
+                res.push(t - BASE);
+                carry = 1;
+            } else {
+                res.push(t);
+                carry = 0;
+            }
+        }
+        res.push(carry);
+        BigUint::from_digits(res)
+    }
+}
+
+impl<'a> ops::Sub<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    /// # Panics
+    /// Panics if the result is negative.
+    fn sub(self, other: &BigUint) -> BigUint {
+        assert!(self >= other, "subtraction overflow");
+        let mut res = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0;
+        for (i, &x) in self.digits.iter().enumerate() {
+            let y = other.digits.get(i).unwrap_or(&0) + borrow;
+            if x >= y {
+                res.push(x - y);
+                borrow = 0;
+            } else {
+                res.push(x + BASE - y);
+                borrow = 1;
+            }
+        }
+        BigUint::from_digits(res)
+    }
+}
+
+impl<'a> ops::Mul<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    /// O(nm) for small inputs and O((n + m) log (n + m)) by NTT for large inputs.
+    fn mul(self, other: &BigUint) -> BigUint {
+        BigUint::from_digits(mul_digits(&self.digits, &other.digits))
+    }
+}
+
+impl<'a> ops::Div<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl<'a> ops::Rem<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+/// Implements operators for owned values by forwarding them to references.
+macro_rules! forward_binop {
+    ($t:ty, $($op:ident $method:ident $op_assign:ident $method_assign:ident),*) => ($(
+        impl ops::$op for $t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                ops::$op::$method(&self, &other)
+            }
+        }
+
+        impl<'a> ops::$op<&'a $t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: &$t) -> $t {
+                ops::$op::$method(&self, other)
+            }
+        }
+
+        impl ops::$op_assign for $t {
+            fn $method_assign(&mut self, other: $t) {
+                *self = ops::$op::$method(&*self, &other);
+            }
+        }
+
+        impl<'a> ops::$op_assign<&'a $t> for $t {
+            fn $method_assign(&mut self, other: &$t) {
+                *self = ops::$op::$method(&*self, other);
+            }
+        }
+    )*)
+}
+
+forward_binop!(BigUint, Add add AddAssign add_assign, Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign, Div div DivAssign div_assign, Rem rem RemAssign rem_assign);
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = match self.digits.last() {
+            Some(x) => x.to_string(),
+            None => "0".to_string(),
+        };
+        for x in self.digits.iter().rev().skip(1) {
+            s.push_str(&format!("{:09}", x));
+        }
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl str::FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    /// Parses a decimal integer with an optional `+` sign.
+    fn from_str(s: &str) -> Result<BigUint, ParseBigIntError> {
+        let s = if s.starts_with('+') { &s[1..] } else { s };
+        if s.is_empty() || !s.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let bytes = s.as_bytes();
+        let mut digits = Vec::with_capacity(bytes.len() / BASE_DIGITS + 1);
+        let mut end = bytes.len();
+        while end > 0 {
+            let start = end.saturating_sub(BASE_DIGITS);
+            let x = bytes[start..end]
+                .iter()
+                .fold(0, |acc, &c| acc * 10 + (c - b'0') as u32);
+            digits.push(x);
+            end = start;
+        }
+        Ok(BigUint::from_digits(digits))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty)*) => ($(
+        impl From<$t> for BigUint {
+            fn from(x: $t) -> BigUint {
+                let mut x = x as u128;
+                let mut digits = Vec::new();
+                while x > 0 {
+                    digits.push((x % BASE as u128) as u32);
+                    x /= BASE as u128;
+                }
+                BigUint { digits }
+            }
+        }
+    )*)
+}
+
+impl_from_unsigned! { u8 u16 u32 u64 u128 usize }
+
+/// Arbitrary-precision signed integer.
+///
+/// Division truncates toward zero, and the remainder has the same sign as the dividend,
+/// which are the same as primitive integers.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{BigInt, Mint107};
+/// let x: BigInt = "-123456789012345678901234567890".parse().unwrap();
+/// let y = BigInt::from(1_000_000_007);
+/// assert_eq!(
+///     "15241578859929888023167208816003659725255296712388355451225133364700",
+///     (&x * &x * &y).to_string()
+/// );
+/// assert_eq!(x, &x / &y * &y + &x % &y);
+/// assert_eq!(-Mint107::from(123_456_789_012_345_678_901_234_567_890u128), x.to_mint());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool, // always false for zero
+    abs: BigUint,
+}
+
+impl BigInt {
+    fn new(negative: bool, abs: BigUint) -> BigInt {
+        BigInt {
+            negative: negative && !abs.is_zero(),
+            abs,
+        }
+    }
+
+    pub fn zero() -> BigInt {
+        BigInt::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.abs.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the absolute value.
+    pub fn abs(&self) -> &BigUint {
+        &self.abs
+    }
+
+    /// Returns `self^e`.
+    pub fn pow(&self, e: u32) -> BigInt {
+        BigInt::new(self.negative && e % 2 == 1, self.abs.pow(e))
+    }
+
+    /// Returns `self` modulo `M`.
+    pub fn to_mint<M: Module>(&self) -> Mint<M> {
+        let x = self.abs.to_mint();
+        if self.negative {
+            -x
+        } else {
+            x
+        }
+    }
+
+    /// Returns `(self / d, self % d)` rounded toward zero.
+    pub fn div_rem(&self, d: &BigInt) -> (BigInt, BigInt) {
+        let (q, r) = self.abs.div_rem(&d.abs);
+        (
+            BigInt::new(self.negative != d.negative, q),
+            BigInt::new(self.negative, r),
+        )
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(x: BigUint) -> BigInt {
+        BigInt::new(false, x)
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.abs.cmp(&other.abs),
+            (true, true) => other.abs.cmp(&self.abs),
+            (false, true) => cmp::Ordering::Greater,
+            (true, false) => cmp::Ordering::Less,
+        }
+    }
+}
+
+impl ops::Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.abs.clone())
+    }
+}
+
+impl ops::Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.abs)
+    }
+}
+
+impl<'a> ops::Add<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            BigInt::new(self.negative, &self.abs + &other.abs)
+        } else if self.abs >= other.abs {
+            BigInt::new(self.negative, &self.abs - &other.abs)
+        } else {
+            BigInt::new(other.negative, &other.abs - &self.abs)
+        }
+    }
+}
+
+impl<'a> ops::Sub<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl<'a> ops::Mul<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(self.negative != other.negative, &self.abs * &other.abs)
+    }
+}
+
+impl<'a> ops::Div<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl<'a> ops::Rem<&'a BigInt> for &'a BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+forward_binop!(BigInt, Add add AddAssign add_assign, Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign, Div div DivAssign div_assign, Rem rem RemAssign rem_assign);
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.abs.to_string())
+    }
+}
+
+impl str::FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// Parses a decimal integer with an optional sign.
+    fn from_str(s: &str) -> Result<BigInt, ParseBigIntError> {
+        if s.starts_with('-') {
+            let abs: BigUint = s[1..].parse()?;
+            if s[1..].starts_with('+') {
+                return Err(ParseBigIntError);
+            }
+            Ok(BigInt::new(true, abs))
+        } else {
+            Ok(BigInt::new(false, s.parse()?))
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty)*) => ($(
+        impl From<$t> for BigInt {
+            fn from(x: $t) -> BigInt {
+                let x = x as i128;
+                // `wrapping_abs` and the cast handle `i128::min_value()` correctly
+                BigInt::new(x < 0, BigUint::from(x.wrapping_abs() as u128))
+            }
+        }
+    )*)
+}
+
+impl_from_signed! { i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    fn random_biguint(rng: &mut XorShift, len: usize) -> BigUint {
+        BigUint::from_digits((0..len).map(|_| rng.next_u32() % BASE).collect())
+    }
+
+    #[test]
+    fn test_small() {
+        let v: Vec<i64> = vec![
+            0,
+            1,
+            -1,
+            999_999_999,
+            1_000_000_000,
+            -1_000_000_001,
+            123_456_789_012,
+            -3_037_000_499,
+            3_037_000_499,
+        ];
+        for &a in &v {
+            let x = BigInt::from(a);
+            assert_eq!(a.to_string(), x.to_string());
+            assert_eq!(Ok(x.clone()), a.to_string().parse());
+            for &b in &v {
+                let y = BigInt::from(b);
+                assert_eq!(BigInt::from(a + b), &x + &y);
+                assert_eq!(BigInt::from(a - b), &x - &y);
+                assert_eq!(BigInt::from(a as i128 * b as i128), &x * &y);
+                assert_eq!(a.cmp(&b), x.cmp(&y));
+                if b != 0 {
+                    assert_eq!(BigInt::from(a / b), &x / &y);
+                    assert_eq!(BigInt::from(a % b), &x % &y);
+                }
+            }
+        }
+        let min = BigInt::from(i128::min_value());
+        assert_eq!(i128::min_value().to_string(), min.to_string());
+        assert_eq!(
+            BigUint::from(u128::max_value()).to_string(),
+            u128::max_value().to_string()
+        );
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let mut rng = XorShift::new();
+        for &(n, m) in &[(1, 1), (3, 2), (10, 5), (100, 70), (300, 200), (1000, 1000)] {
+            let a = random_biguint(&mut rng, n);
+            let b = random_biguint(&mut rng, m);
+            let c = &a * &b;
+            // compare with naive multiplication
+            let mut naive = BigUint::zero();
+            for (i, &d) in b.digits.iter().enumerate() {
+                let mut t = BigUint::from_digits(mul_small(&a.digits, d));
+                t.digits.splice(0..0, vec![0; i]);
+                naive += BigUint::from_digits(t.digits);
+            }
+            assert_eq!(naive, c);
+            let r = random_biguint(&mut rng, m.saturating_sub(1));
+            let x = &c + &r;
+            if !b.is_zero() && r < b {
+                assert_eq!((a.clone(), r.clone()), x.div_rem(&b));
+            }
+            assert_eq!(x, &(&x / &a) * &a + &x % &a);
+        }
+    }
+
+    #[test]
+    fn test_div_edge() {
+        // cases where the estimated quotient digit needs corrections
+        let b = BigUint::from_digits(vec![0, 500_000_000, 1]);
+        let x = BigUint::from_digits(vec![999_999_999, 999_999_999, 999_999_999, 999_999_999]);
+        let (q, r) = x.div_rem(&b);
+        assert!(r < b);
+        assert_eq!(x, &q * &b + r);
+        let p = BigUint::from(10u32).pow(100);
+        let (q, r) = (&p - &BigUint::from(1u32)).div_rem(&BigUint::from(10u32).pow(50));
+        assert_eq!(BigUint::from(10u32).pow(50) - BigUint::from(1u32), q);
+        assert_eq!(q, r);
+    }
+
+    #[test]
+    fn test_parse_and_mint() {
+        use crate::math::Mint998;
+        let s = "98765432109876543210987654321098765432109876543210";
+        let x: BigInt = s.parse().unwrap();
+        assert_eq!(s, x.to_string());
+        assert_eq!(format!("-{}", s), (-&x).to_string());
+        let expected = s
+            .bytes()
+            .fold(Mint998::from(0), |acc, c| acc * 10 + (c - b'0') as u32);
+        assert_eq!(expected, x.to_mint());
+        assert_eq!(-expected, (-x).to_mint());
+        assert_eq!("000123".parse::<BigUint>().unwrap().to_string(), "123");
+        assert_eq!("-0".parse::<BigInt>().unwrap(), BigInt::zero());
+        assert!(!"-0".parse::<BigInt>().unwrap().is_negative());
+        for &bad in &["", "-", "+", "1a", "--1", "-+1", " 1"] {
+            assert_eq!(Err(ParseBigIntError), bad.parse::<BigInt>());
+        }
+        assert_eq!(Err(ParseBigIntError), "-1".parse::<BigUint>());
+        assert_eq!("    -42", format!("{:>7}", BigInt::from(-42)));
+    }
+}
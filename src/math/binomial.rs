@@ -0,0 +1,197 @@
+//! Binomial coefficients modulo an arbitrary (possibly composite) number
+use super::euclid::{crt, inv_mod};
+use super::prime::factorize;
+
+/// Binomial coefficients `nCr mod m` for any `m >= 1`.
+///
+/// [`Comb`](struct.Comb.html) only works for prime modules.
+/// This struct factorizes `m` into prime powers `p^e`, computes `nCr mod p^e`
+/// by the generalized Lucas' theorem (Granville) and combines them by CRT.
+///
+/// A table of Pascal's triangle can be used instead for small `n`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::Binomial;
+/// let b = Binomial::new(1_000_000);
+/// assert_eq!(184_756, b.comb(20, 10));
+/// // C(100, 50) = 100891344545564193334812497256
+/// assert_eq!(497_256, b.comb(100, 50));
+///
+/// let t = Binomial::with_table(100, 1_000_000);
+/// assert_eq!(497_256, t.comb(100, 50));
+/// ```
+pub struct Binomial {
+    m: u64,
+    prime_powers: Vec<PrimePower>,
+    pascal: Vec<Vec<u64>>,
+}
+
+/// Precomputation for `nCr mod p^e`.
+struct PrimePower {
+    p: u64,
+    e: u32,
+    q: u64, // p^e
+    // prod[i] is the product of integers in `1..=i` coprime to `p` modulo `q`
+    prod: Vec<u64>,
+}
+
+impl PrimePower {
+    fn new(p: u64, e: u32) -> PrimePower {
+        let q = p.pow(e);
+        let mut prod = vec![1 % q; q as usize];
+        for i in 1..q as usize {
+            prod[i] = if i as u64 % p == 0 {
+                prod[i - 1]
+            } else {
+                mul_mod(prod[i - 1], i as u64, q)
+            };
+        }
+        PrimePower { p, e, q, prod }
+    }
+
+    /// Returns `(k, x)` such that `n! = p^k * y` and `y mod q = x` where `y` is coprime to `p`.
+    fn fact(&self, mut n: u64) -> (u64, u64) {
+        let mut k = 0;
+        let mut x = 1 % self.q;
+        let full = self.prod[self.q as usize - 1]; // it is 1 or -1
+        while n > 0 {
+            if (n / self.q) % 2 == 1 {
+                x = mul_mod(x, full, self.q);
+            }
+            x = mul_mod(x, self.prod[(n % self.q) as usize], self.q);
+            n /= self.p;
+            k += n;
+        }
+        (k, x)
+    }
+
+    fn comb(&self, n: u64, r: u64) -> u64 {
+        let (k0, x0) = self.fact(n);
+        let (k1, x1) = self.fact(r);
+        let (k2, x2) = self.fact(n - r);
+        let k = k0 - k1 - k2;
+        if k >= self.e as u64 {
+            return 0;
+        }
+        let den = mul_mod(x1, x2, self.q);
+        let inv = inv_mod(den as i64, self.q).unwrap();
+        mul_mod(mul_mod(x0, inv, self.q), self.p.pow(k as u32), self.q)
+    }
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+impl Binomial {
+    /// Creates an object to compute binomial coefficients modulo `m`.
+    ///
+    /// This requires `O(sum of p^e)` time and memory for prime powers `p^e` dividing `m`,
+    /// so `m` shouldn't have a large prime power factor.
+    pub fn new(m: u64) -> Binomial {
+        assert!(m >= 1);
+        let prime_powers = factorize(m)
+            .into_iter()
+            .map(|(p, e)| PrimePower::new(p, e))
+            .collect();
+        Binomial {
+            m,
+            prime_powers,
+            pascal: Vec::new(),
+        }
+    }
+
+    /// Creates an object with a table of Pascal's triangle for `nCr` where `n <= n_max`.
+    ///
+    /// This requires `O(n_max^2)` time and memory but works for any `m`.
+    /// Inputs beyond the table are not supported.
+    pub fn with_table(n_max: usize, m: u64) -> Binomial {
+        assert!(m >= 1);
+        let mut pascal: Vec<Vec<u64>> = Vec::with_capacity(n_max + 1);
+        for n in 0..=n_max {
+            let mut row = vec![1 % m; n + 1];
+            for r in 1..n {
+                let (a, b) = (pascal[n - 1][r - 1], pascal[n - 1][r]);
+                // a + b may overflow if m > 2^63
+                row[r] = if a >= m - b { a - (m - b) } else { a + b };
+            }
+            pascal.push(row);
+        }
+        Binomial {
+            m,
+            prime_powers: Vec::new(),
+            pascal,
+        }
+    }
+
+    /// Returns the module number.
+    pub fn module(&self) -> u64 {
+        self.m
+    }
+
+    /// `nCr mod m`
+    ///
+    /// `O(1)` with a table, `O(log n)` for each prime power otherwise.
+    pub fn comb(&self, n: u64, r: u64) -> u64 {
+        if r > n {
+            return 0;
+        }
+        if let Some(row) = self.pascal.get(n as usize) {
+            return row[r as usize];
+        }
+        assert!(
+            self.pascal.is_empty(),
+            "n is out of the range of Pascal's triangle"
+        );
+        let mut rems = Vec::with_capacity(self.prime_powers.len());
+        let mut mods = Vec::with_capacity(self.prime_powers.len());
+        for pp in &self.prime_powers {
+            rems.push(pp.comb(n, r) as i64);
+            mods.push(pp.q);
+        }
+        crt(&rems, &mods).unwrap().0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Comb, Module};
+
+    #[test]
+    fn test_binomial() {
+        for &m in &[1, 2, 4, 12, 27, 64, 360, 720_720, 1_000_000, 999_983] {
+            let n = 200;
+            let t = Binomial::with_table(n, m);
+            let b = Binomial::new(m);
+            assert_eq!(m, b.module());
+            for i in 0..=n as u64 {
+                for j in 0..=i + 1 {
+                    assert_eq!(t.comb(i, j), b.comb(i, j), "{}C{} mod {}", i, j, m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        #[derive(Clone, Copy, Debug)]
+        struct Mod;
+        impl Module for Mod {
+            fn module() -> u32 {
+                999_983
+            }
+        }
+        // C(n, r) for prime module must agree with Lucas' theorem
+        let p = 999_983;
+        let b = Binomial::new(p);
+        let c: Comb<Mod> = Comb::new(0);
+        for &(n, r) in &[(1_000_000, 500_000), (123_456_789_012, 1_234), (p + 5, 3)] {
+            assert_eq!(u64::from(c.comb(n, r)), b.comb(n, r));
+        }
+        // C(2^k, 2^(k-1)) is divisible by 2 exactly once
+        let b = Binomial::new(1 << 20);
+        assert_eq!(2, b.comb(1 << 40, 1 << 39) % 4);
+    }
+}
@@ -1,7 +1,18 @@
+use super::convolution::convolution_any;
 use super::mint::{Mint, Module};
+use std::cell::RefCell;
+
+/// Tables are not extended beyond this size automatically to avoid huge allocations.
+const MAX_TABLE_SIZE: usize = 1 << 22;
 
 /// Useful struct to compute combinations
 ///
+/// The module should be prime. Use [`Binomial`](struct.Binomial.html) for composite modules.
+///
+/// Tables of factorials grow automatically when a larger input is given,
+/// so `Comb::new(0)` also works.
+/// The tables are kept in a `RefCell`, so `Comb` is `!Sync`.
+///
 /// # Examples
 /// ```
 /// use algorithms::math::{Comb, Mod107, Mint107};
@@ -11,37 +22,82 @@ use super::mint::{Mint, Module};
 /// assert_eq!(Mint107::from(12), comb.perm(4, 2));
 /// assert_eq!(Mint107::from(6), comb.comb(4, 2));
 /// assert_eq!(Mint107::from(10), comb.multi_comb(4, 2));
+/// assert_eq!(Mint107::from(42), comb.catalan(5));
+/// assert_eq!(Mint107::from(52), comb.bell(5));
 /// ```
 pub struct Comb<M: Module> {
+    table: RefCell<Table<M>>,
+}
+
+struct Table<M: Module> {
     fact: Vec<Mint<M>>,
     factinv: Vec<Mint<M>>,
 }
 
+impl<M: Module> Table<M> {
+    /// Extends tables to cover `0..=n`.
+    fn extend(&mut self, n: usize) {
+        let old = self.fact.len();
+        if n < old {
+            return;
+        }
+        let new = (n + 1).max(2 * old).min(MAX_TABLE_SIZE.max(n + 1));
+        let new = new.min(M::module() as usize);
+        for i in old..new {
+            let x = if i == 0 {
+                Mint::from(1)
+            } else {
+                self.fact[i - 1] * i
+            };
+            self.fact.push(x);
+        }
+        self.factinv.resize(new, Mint::from(0));
+        self.factinv[new - 1] = self.fact[new - 1].inv();
+        for i in (old..new - 1).rev() {
+            self.factinv[i] = self.factinv[i + 1] * (i + 1);
+        }
+    }
+}
+
 impl<M: Module> Comb<M> {
     /// Create a object that provides effiecint computation of combinations
     /// for input smaller than `n`.
     ///
     /// This requires `O(n)` time.
     pub fn new(n: usize) -> Comb<M> {
-        let mut fact: Vec<Mint<M>> = vec![0.into(); n + 1];
-        let mut factinv: Vec<Mint<M>> = vec![0.into(); n + 1];
-        fact[0] = 1.into();
-        for i in 0..n {
-            fact[i + 1] = fact[i] * (i + 1);
+        let mut table = Table {
+            fact: Vec::new(),
+            factinv: Vec::new(),
+        };
+        table.extend(n.min(M::module() as usize - 1));
+        Comb {
+            table: RefCell::new(table),
         }
-        factinv[n] = fact[n].inv();
-        for i in (0..n).rev() {
-            factinv[i] = factinv[i + 1] * (i + 1);
+    }
+
+    /// Returns `(n!, 1 / n!)` if `n` is (or can be) covered by tables.
+    fn lookup(&self, n: u64) -> Option<(Mint<M>, Mint<M>)> {
+        if n >= M::module() as u64 {
+            return None;
+        }
+        let n = n as usize;
+        let mut table = self.table.borrow_mut();
+        if n >= table.fact.len() && n < MAX_TABLE_SIZE {
+            table.extend(n);
+        }
+        if n < table.fact.len() {
+            Some((table.fact[n], table.factinv[n]))
+        } else {
+            None
         }
-        Comb { fact, factinv }
     }
 
     /// `n! = 1 * 2 * ... * n`
     ///
-    /// `O(1)` if n is smaller than input in `new` method.
+    /// Amortized `O(1)` if n is smaller than `2^22`.
     pub fn fact(&self, n: u64) -> Mint<M> {
-        if let Some(x) = self.fact.get(n as usize) {
-            *x
+        if let Some((x, _)) = self.lookup(n) {
+            x
         } else if n >= M::module() as u64 {
             Mint::from(0)
         } else {
@@ -57,21 +113,35 @@ impl<M: Module> Comb<M> {
 
     /// returns `y` such that `n! * y == 1`.
     ///
-    /// `O(1)` if n is smaller than input in `new` method.
+    /// Amortized `O(1)` if n is smaller than `2^22`.
     pub fn factinv(&self, n: u64) -> Mint<M> {
-        if let Some(x) = self.factinv.get(n as usize) {
-            *x
+        if let Some((_, x)) = self.lookup(n) {
+            x
         } else {
             self.fact(n).inv()
         }
     }
 
+    /// returns `y` such that `n * y == 1` (`n` should not be a multiple of the module).
+    ///
+    /// Amortized `O(1)` if n is smaller than `2^22`.
+    pub fn inv(&self, n: u64) -> Mint<M> {
+        assert!(n % M::module() as u64 != 0);
+        if n < M::module() as u64 {
+            if let (Some((_, a)), Some((b, _))) = (self.lookup(n), self.lookup(n - 1)) {
+                return a * b;
+            }
+        }
+        Mint::<M>::from(n).inv()
+    }
+
     /// `nPr = n! / (n - r)!`
     ///
-    /// `O(1)` if n and r are smaller than input in `new` method.
+    /// Amortized `O(1)` if `n` is smaller than `2^22` or the size given to `new`.
+    /// Tables don't grow beyond that, so it takes `O(n)` for larger `n`.
     pub fn perm(&self, n: u64, r: u64) -> Mint<M> {
         if n >= r {
-            self.fact(n) * self.factinv((n - r) as u64)
+            self.fact(n) * self.factinv(n - r)
         } else {
             0.into()
         }
@@ -79,7 +149,8 @@ impl<M: Module> Comb<M> {
 
     /// `nCr = n! / (n - r)! / r!`.
     ///
-    /// `O(1)` if n and r are smaller than input in `new` method.
+    /// Amortized `O(1)` if `n` is smaller than `2^22` or the size given to `new`.
+    /// Tables don't grow beyond that, so it takes `O(n)` for larger `n`.
     pub fn comb(&self, n: u64, r: u64) -> Mint<M> {
         let m = M::module() as u64;
         if n >= m {
@@ -93,7 +164,7 @@ impl<M: Module> Comb<M> {
 
     /// `(n + k - 1)! / k!`.
     ///
-    /// `O(1)` if n and r are smaller than input in `new` method.
+    /// Same complexity as [`comb`](#method.comb) for `n + r - 1`.
     pub fn multi_comb(&self, n: u64, r: u64) -> Mint<M> {
         if r == 0 {
             Mint::from(1)
@@ -101,6 +172,143 @@ impl<M: Module> Comb<M> {
             self.comb(n + r - 1, r)
         }
     }
+
+    /// Multinomial coefficient `(k[0] + k[1] + ...)! / (k[0]! * k[1]! * ...)`.
+    ///
+    /// `O(k.len())`
+    pub fn multinomial(&self, k: &[u64]) -> Mint<M> {
+        let mut res = Mint::from(1);
+        let mut sum = 0;
+        for &x in k {
+            sum += x;
+            res *= self.comb(sum, x);
+        }
+        res
+    }
+
+    /// Catalan number `C(2n, n) / (n + 1)`.
+    pub fn catalan(&self, n: u64) -> Mint<M> {
+        self.comb(2 * n, n) - self.comb(2 * n, n + 1)
+    }
+
+    /// Stirling number of the second kind `S(n, k)`,
+    /// the number of ways to partition `n` labelled items into `k` non-empty sets.
+    ///
+    /// `O(k log n)`. `k` should be smaller than the module.
+    pub fn stirling2(&self, n: u64, k: u64) -> Mint<M> {
+        // S(n, k) = sum_{i=0}^{k} (-1)^(k-i) C(k, i) i^n / k!
+        let mut res = Mint::from(0);
+        for i in 0..=k {
+            let x = self.comb(k, i) * Mint::from(i).pow(n);
+            if (k - i) % 2 == 0 {
+                res += x;
+            } else {
+                res -= x;
+            }
+        }
+        res * self.factinv(k)
+    }
+
+    /// Returns `S(n, k)` for `k = 0..=n` (Stirling numbers of the second kind).
+    ///
+    /// `O(n log n)`. `n` should be smaller than the module.
+    pub fn stirling2_row(&self, n: usize) -> Vec<Mint<M>> {
+        let a: Vec<Mint<M>> = (0..=n)
+            .map(|i| Mint::from(i).pow(n as u64) * self.factinv(i as u64))
+            .collect();
+        let b: Vec<Mint<M>> = (0..=n)
+            .map(|j| {
+                if j % 2 == 0 {
+                    self.factinv(j as u64)
+                } else {
+                    -self.factinv(j as u64)
+                }
+            })
+            .collect();
+        let mut res = convolution_any(&a, &b);
+        res.truncate(n + 1);
+        res
+    }
+
+    /// Unsigned Stirling number of the first kind `[n, k]`,
+    /// the number of permutations of `n` items with `k` cycles.
+    ///
+    /// `O(n log^2 n)`
+    pub fn stirling1(&self, n: usize, k: usize) -> Mint<M> {
+        self.stirling1_row(n)
+            .get(k)
+            .cloned()
+            .unwrap_or_else(|| Mint::from(0))
+    }
+
+    /// Returns `[n, k]` for `k = 0..=n` (unsigned Stirling numbers of the first kind),
+    /// which are coefficients of `x (x + 1) ... (x + n - 1)`.
+    ///
+    /// `O(n log^2 n)`
+    pub fn stirling1_row(&self, n: usize) -> Vec<Mint<M>> {
+        fn rec<M: Module>(l: usize, r: usize) -> Vec<Mint<M>> {
+            if r - l == 1 {
+                return vec![Mint::from(l), Mint::from(1)];
+            }
+            let mid = (l + r) / 2;
+            convolution_any(&rec(l, mid), &rec(mid, r))
+        }
+        if n == 0 {
+            vec![Mint::from(1)]
+        } else {
+            rec(0, n)
+        }
+    }
+
+    /// Bell number `B(n)`, the number of ways to partition `n` labelled items.
+    ///
+    /// `O(n log n)`. `n` should be smaller than the module.
+    pub fn bell(&self, n: u64) -> Mint<M> {
+        // B(n) = sum_{i=0}^{n} i^n / i! * sum_{j=0}^{n-i} (-1)^j / j!
+        let mut alt = vec![Mint::from(0); n as usize + 1];
+        let mut sum = Mint::from(0);
+        for (j, x) in alt.iter_mut().enumerate() {
+            if j % 2 == 0 {
+                sum += self.factinv(j as u64);
+            } else {
+                sum -= self.factinv(j as u64);
+            }
+            *x = sum;
+        }
+        (0..=n)
+            .map(|i| Mint::from(i).pow(n) * self.factinv(i) * alt[(n - i) as usize])
+            .sum()
+    }
+
+    /// Returns partition numbers `p(0), p(1), ..., p(n)`, where `p(i)` is
+    /// the number of ways to write `i` as a sum of positive integers.
+    ///
+    /// `O(n sqrt(n))` by Euler's pentagonal number theorem.
+    pub fn partitions(&self, n: usize) -> Vec<Mint<M>> {
+        let mut p = vec![Mint::from(0); n + 1];
+        p[0] = Mint::from(1);
+        for i in 1..=n {
+            let mut k = 1;
+            loop {
+                let g1 = k * (3 * k - 1) / 2;
+                if g1 > i {
+                    break;
+                }
+                let g2 = k * (3 * k + 1) / 2;
+                let mut x = p[i - g1];
+                if g2 <= i {
+                    x += p[i - g2];
+                }
+                if k % 2 == 1 {
+                    p[i] += x;
+                } else {
+                    p[i] -= x;
+                }
+                k += 1;
+            }
+        }
+        p
+    }
 }
 
 #[cfg(test)]
@@ -139,4 +347,88 @@ mod tests {
         }
         assert_eq!(f, c.fact(p));
     }
+
+    #[test]
+    fn test_lazy() {
+        use crate::math::{Mint107, Mod107};
+        let c = Comb::<Mod107>::new(0);
+        assert_eq!(Mint107::from(3_628_800), c.fact(10));
+        assert_eq!(Mint107::from(1), c.fact(1000) * c.factinv(1000));
+        assert_eq!(Mint107::from(184_756), c.comb(20, 10));
+        for i in 1..2000 {
+            assert_eq!(Mint107::from(1), c.inv(i) * i);
+        }
+    }
+
+    #[test]
+    fn test_small_module() {
+        #[derive(Clone, Copy, Debug)]
+        struct Mod;
+        impl Module for Mod {
+            fn module() -> u32 {
+                7
+            }
+        }
+        let c = Comb::<Mod>::new(100);
+        assert_eq!(Mint::from(0), c.fact(7));
+        assert_eq!(Mint::from(720), c.fact(6));
+        assert_eq!(Mint::from(3), c.inv(5));
+        // C(10, 3) = 120
+        assert_eq!(Mint::from(120), c.comb(10, 3));
+    }
+
+    #[test]
+    fn test_numbers() {
+        use crate::math::{Mint998, Mod998};
+        let c = Comb::<Mod998>::new(0);
+        let catalan = [1, 1, 2, 5, 14, 42, 132, 429, 1430];
+        for (n, &x) in catalan.iter().enumerate() {
+            assert_eq!(Mint998::from(x), c.catalan(n as u64));
+        }
+        let bell = [1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147];
+        for (n, &x) in bell.iter().enumerate() {
+            assert_eq!(Mint998::from(x), c.bell(n as u64));
+        }
+        let partitions = [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42, 56];
+        let p = c.partitions(partitions.len() - 1);
+        assert_eq!(
+            partitions
+                .iter()
+                .map(|&x| Mint998::from(x))
+                .collect::<Vec<_>>(),
+            p
+        );
+        assert_eq!(Mint998::from(190_569_292), c.partitions(100)[100]);
+        assert_eq!(Mint998::from(1260), c.multinomial(&[2, 3, 4]));
+        assert_eq!(Mint998::from(1), c.multinomial(&[]));
+    }
+
+    #[test]
+    fn test_stirling() {
+        use crate::math::{Mint998, Mod998};
+        let c = Comb::<Mod998>::new(0);
+        let n = 100;
+        // s1[i][k] and s2[i][k] by recurrences
+        let mut s1 = vec![vec![Mint998::from(0); n + 1]; n + 1];
+        let mut s2 = vec![vec![Mint998::from(0); n + 1]; n + 1];
+        s1[0][0] = Mint998::from(1);
+        s2[0][0] = Mint998::from(1);
+        for i in 1..=n {
+            for k in 1..=i {
+                s1[i][k] = s1[i - 1][k - 1] + s1[i - 1][k] * (i - 1);
+                s2[i][k] = s2[i - 1][k - 1] + s2[i - 1][k] * k;
+            }
+        }
+        for i in 0..=n {
+            assert_eq!(&s1[i][..=i], &c.stirling1_row(i)[..]);
+            assert_eq!(&s2[i][..=i], &c.stirling2_row(i)[..]);
+        }
+        for &(i, k) in &[(0, 0), (5, 2), (30, 7), (100, 50), (10, 11)] {
+            assert_eq!(s1[i][k.min(n)] * (k <= i) as u32, c.stirling1(i, k));
+            assert_eq!(
+                s2[i][k.min(n)] * (k <= i) as u32,
+                c.stirling2(i as u64, k as u64)
+            );
+        }
+    }
 }
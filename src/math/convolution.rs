@@ -0,0 +1,348 @@
+//! Convolution of sequences by number-theoretic transform (NTT)
+//!
+//! * [`convolution`](fn.convolution.html) - for NTT-friendly modules such as `MOD_998`
+//! * [`convolution_any`](fn.convolution_any.html) - for any module
+//! * [`convolution_u64`](fn.convolution_u64.html), [`convolution_i64`](fn.convolution_i64.html) - exact results
+use super::mint::{Mint, Module, MOD_998};
+
+// input shorter than this is computed by the naive algorithm
+const NAIVE_THRESHOLD: usize = 60;
+
+// NTT-friendly primes used for convolution with arbitrary module.
+// `p - 1` is divisible by `2^24` for all of them.
+const MOD_NTT1: u32 = 754_974_721; // 45 * 2^24 + 1
+const MOD_NTT2: u32 = 167_772_161; // 5 * 2^25 + 1
+const MOD_NTT3: u32 = 469_762_049; // 7 * 2^26 + 1
+
+#[derive(Debug, Copy, Clone)]
+pub(super) struct ModNtt1;
+impl Module for ModNtt1 {
+    fn module() -> u32 {
+        MOD_NTT1
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(super) struct ModNtt2;
+impl Module for ModNtt2 {
+    fn module() -> u32 {
+        MOD_NTT2
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(super) struct ModNtt3;
+impl Module for ModNtt3 {
+    fn module() -> u32 {
+        MOD_NTT3
+    }
+}
+
+/// Returns a primitive root of `module()`, which is hard-coded for common NTT-friendly primes.
+fn ntt_root<M: Module>() -> Mint<M> {
+    match M::module() {
+        MOD_998 | MOD_NTT2 | MOD_NTT3 => Mint::from(3),
+        MOD_NTT1 => Mint::from(11),
+        _ => Mint::primitive_root(),
+    }
+}
+
+/// In-place number-theoretic transform with a primitive root `g` of `module()`.
+/// `a.len()` should be a power of two.
+/// Computes the inverse transform if `invert` is `true`.
+fn ntt<M: Module>(a: &mut [Mint<M>], g: Mint<M>, invert: bool) {
+    let n = a.len();
+    let p = M::module() as u64;
+    assert!(n.is_power_of_two());
+    assert!(
+        (p - 1) % n as u64 == 0,
+        "module {} doesn't support NTT of length {}",
+        p,
+        n
+    );
+
+    // bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let g = if invert { g.inv() } else { g };
+    let mut len = 2;
+    while len <= n {
+        let w = g.pow((p - 1) / len as u64);
+        let half = len / 2;
+        let mut ws = Vec::with_capacity(half);
+        let mut cur = Mint::from(1);
+        for _ in 0..half {
+            ws.push(cur);
+            cur *= w;
+        }
+        for block in a.chunks_mut(len) {
+            let (lo, hi) = block.split_at_mut(half);
+            for k in 0..half {
+                let u = lo[k];
+                let v = hi[k] * ws[k];
+                lo[k] = u + v;
+                hi[k] = u - v;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let inv_n = Mint::<M>::from(n).inv();
+        for x in a.iter_mut() {
+            *x *= inv_n;
+        }
+    }
+}
+
+fn convolution_naive<M: Module>(a: &[Mint<M>], b: &[Mint<M>]) -> Vec<Mint<M>> {
+    let mut res = vec![Mint::from(0); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            res[i + j] += x * y;
+        }
+    }
+    res
+}
+
+/// Returns `c` where `c[k]` is the sum of `a[i] * b[j]` for `i + j == k`.
+///
+/// `module()` should be a prime `p` such that `p - 1` is divisible by
+/// the smallest power of two not less than `a.len() + b.len() - 1`
+/// (e.g. `MOD_998 = 119 * 2^23 + 1`). Use [`convolution_any`](fn.convolution_any.html) otherwise.
+///
+/// O((n + m) log (n + m))
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{convolution, Mint998};
+/// let a: Vec<Mint998> = vec![1.into(), 2.into(), 3.into()];
+/// let b: Vec<Mint998> = vec![4.into(), 5.into()];
+/// let c: Vec<Mint998> = vec![4.into(), 13.into(), 22.into(), 15.into()];
+/// assert_eq!(c, convolution(&a, &b));
+/// ```
+pub fn convolution<M: Module>(a: &[Mint<M>], b: &[Mint<M>]) -> Vec<Mint<M>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        return convolution_naive(a, b);
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    fa.resize(n, Mint::from(0));
+    let mut fb = b.to_vec();
+    fb.resize(n, Mint::from(0));
+    let g = ntt_root::<M>();
+    ntt(&mut fa, g, false);
+    ntt(&mut fb, g, false);
+    for (x, &y) in fa.iter_mut().zip(fb.iter()) {
+        *x *= y;
+    }
+    ntt(&mut fa, g, true);
+    fa.truncate(len);
+    fa
+}
+
+/// Convolution modulo three NTT-friendly primes and
+/// restores each value modulo `MOD_NTT1 * MOD_NTT2 * MOD_NTT3 ~ 2^85` by Garner's algorithm.
+pub(super) fn convolution_crt<T>(a: &[T], b: &[T]) -> Vec<u128>
+where
+    T: Copy + Into<Mint<ModNtt1>> + Into<Mint<ModNtt2>> + Into<Mint<ModNtt3>>,
+{
+    fn conv<M: Module, T: Copy + Into<Mint<M>>>(a: &[T], b: &[T]) -> Vec<Mint<M>> {
+        let a: Vec<Mint<M>> = a.iter().map(|&x| x.into()).collect();
+        let b: Vec<Mint<M>> = b.iter().map(|&x| x.into()).collect();
+        convolution(&a, &b)
+    }
+    let c1 = conv::<ModNtt1, T>(a, b);
+    let c2 = conv::<ModNtt2, T>(a, b);
+    let c3 = conv::<ModNtt3, T>(a, b);
+
+    let m1 = MOD_NTT1 as u128;
+    let m2 = MOD_NTT2 as u128;
+    let inv_m1_mod2 = Mint::<ModNtt2>::from(MOD_NTT1).inv();
+    let inv_m1m2_mod3 = (Mint::<ModNtt3>::from(MOD_NTT1) * MOD_NTT2).inv();
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((r1, r2), r3)| {
+            let x1 = r1.val();
+            let x2 = (r2 - x1) * inv_m1_mod2;
+            let x3 = (r3 - x1 - Mint::<ModNtt3>::from(MOD_NTT1) * x2.val()) * inv_m1m2_mod3;
+            x1 as u128 + x2.val() as u128 * m1 + x3.val() as u128 * m1 * m2
+        })
+        .collect()
+}
+
+/// Returns `c` where `c[k]` is the sum of `a[i] * b[j]` for `i + j == k` for any module.
+///
+/// This computes convolution with three NTT-friendly primes and combines the results by CRT.
+/// The length of the result should be at most `2^24`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{convolution_any, Mint107};
+/// let a: Vec<Mint107> = vec![1_000_000.into(); 100];
+/// let c = convolution_any(&a, &a);
+/// assert_eq!(Mint107::from(1_000_000_000_000i64) * 100, c[99]);
+/// ```
+pub fn convolution_any<M: Module>(a: &[Mint<M>], b: &[Mint<M>]) -> Vec<Mint<M>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        return convolution_naive(a, b);
+    }
+    let a: Vec<u64> = a.iter().map(|x| x.val() as u64).collect();
+    let b: Vec<u64> = b.iter().map(|x| x.val() as u64).collect();
+    let m = M::module() as u128;
+    convolution_crt(&a, &b)
+        .into_iter()
+        .map(|x| Mint::from((x % m) as u64))
+        .collect()
+}
+
+/// Returns `c` where `c[k]` is the sum of `a[i] * b[j]` for `i + j == k`.
+///
+/// The result is exact if every `c[k]` is less than `2^64`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::convolution_u64;
+/// let a = vec![1 << 40, 1];
+/// let b = vec![1 << 20, 3];
+/// assert_eq!(vec![1 << 60, 3 << 40 | 1 << 20, 3], convolution_u64(&a, &b));
+/// ```
+pub fn convolution_u64(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    convolution_crt(a, b)
+        .into_iter()
+        .map(|x| x as u64)
+        .collect()
+}
+
+/// Returns `c` where `c[k]` is the sum of `a[i] * b[j]` for `i + j == k`.
+///
+/// The result is exact if every `c[k]` fits in `i64`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::convolution_i64;
+/// let a = vec![-1_000_000_000_000, 1];
+/// let b = vec![1_000_000, -3];
+/// let c = vec![-1_000_000_000_000_000_000, 3_000_000_000_000 + 1_000_000, -3];
+/// assert_eq!(c, convolution_i64(&a, &b));
+/// ```
+pub fn convolution_i64(a: &[i64], b: &[i64]) -> Vec<i64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    // negative values are restored from the representatives modulo MOD_NTT1 * MOD_NTT2 * MOD_NTT3
+    let m = MOD_NTT1 as u128 * MOD_NTT2 as u128 * MOD_NTT3 as u128;
+    convolution_crt(a, b)
+        .into_iter()
+        .map(|x| {
+            if x > m / 2 {
+                (x as i128 - m as i128) as i64
+            } else {
+                x as i64
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Mint107, Mint998, Mod998};
+    use crate::util::XorShift;
+
+    fn random_vec(rng: &mut XorShift, len: usize) -> Vec<u64> {
+        (0..len)
+            .map(|_| (rng.next_u32() as u64) << 32 | rng.next_u32() as u64)
+            .collect()
+    }
+
+    #[test]
+    fn test_convolution() {
+        let mut rng = XorShift::new();
+        for &(n, m) in &[(1, 1), (1, 100), (61, 61), (100, 300), (513, 512)] {
+            let a: Vec<Mint998> = random_vec(&mut rng, n)
+                .into_iter()
+                .map(Mint998::from)
+                .collect();
+            let b: Vec<Mint998> = random_vec(&mut rng, m)
+                .into_iter()
+                .map(Mint998::from)
+                .collect();
+            assert_eq!(convolution_naive(&a, &b), convolution(&a, &b));
+            assert_eq!(convolution_naive(&a, &b), convolution_any(&a, &b));
+
+            let a: Vec<Mint107> = random_vec(&mut rng, n)
+                .into_iter()
+                .map(Mint107::from)
+                .collect();
+            let b: Vec<Mint107> = random_vec(&mut rng, m)
+                .into_iter()
+                .map(Mint107::from)
+                .collect();
+            assert_eq!(convolution_naive(&a, &b), convolution_any(&a, &b));
+        }
+        assert!(convolution::<Mod998>(&[], &[1.into()]).is_empty());
+    }
+
+    #[test]
+    fn test_ntt_root() {
+        assert_eq!(Mint::<Mod998>::primitive_root(), ntt_root::<Mod998>());
+        assert_eq!(Mint::<ModNtt1>::primitive_root(), ntt_root::<ModNtt1>());
+        assert_eq!(Mint::<ModNtt2>::primitive_root(), ntt_root::<ModNtt2>());
+        assert_eq!(Mint::<ModNtt3>::primitive_root(), ntt_root::<ModNtt3>());
+    }
+
+    #[test]
+    fn test_convolution_integer() {
+        let mut rng = XorShift::new();
+        for &(n, m) in &[(1, 1), (3, 100), (100, 200)] {
+            let a: Vec<u64> = random_vec(&mut rng, n)
+                .into_iter()
+                .map(|x| x >> 40)
+                .collect();
+            let b: Vec<u64> = random_vec(&mut rng, m)
+                .into_iter()
+                .map(|x| x >> 40)
+                .collect();
+            let mut expected = vec![0; n + m - 1];
+            for i in 0..n {
+                for j in 0..m {
+                    expected[i + j] += a[i] * b[j];
+                }
+            }
+            assert_eq!(expected, convolution_u64(&a, &b));
+
+            let a: Vec<i64> = a.into_iter().map(|x| x as i64 - (1 << 23)).collect();
+            let b: Vec<i64> = b.into_iter().map(|x| x as i64 - (1 << 23)).collect();
+            let mut expected = vec![0; n + m - 1];
+            for i in 0..n {
+                for j in 0..m {
+                    expected[i + j] += a[i] * b[j];
+                }
+            }
+            assert_eq!(expected, convolution_i64(&a, &b));
+        }
+    }
+}
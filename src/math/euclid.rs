@@ -0,0 +1,339 @@
+//! Functions based on the Euclidean algorithm for arbitrary (possibly composite) modules
+//!
+//! Intermediate values are computed with 128-bit integers so that they never overflow.
+// ref: https://github.com/atcoder/ac-library/blob/master/atcoder/math.hpp
+use std::mem;
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g` where `g = gcd(a, b) >= 0`.
+///
+/// # Panics
+/// Panics if `g` doesn't fit in `i64`, which happens only when both `a` and `b` are in `{0, i64::MIN}`
+/// and not both zero (e.g. `ext_gcd(i64::MIN, 0)`).
+///
+/// # Examples
+/// ```
+/// use algorithms::math::ext_gcd;
+/// let (g, x, y) = ext_gcd(240, 46);
+/// assert_eq!(2, g);
+/// assert_eq!(2, 240 * x + 46 * y);
+/// ```
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut a, mut b) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1i128, 0i128);
+    let (mut y0, mut y1) = (0i128, 1i128);
+    while b != 0 {
+        let q = a / b;
+        a -= q * b;
+        mem::swap(&mut a, &mut b);
+        x0 -= q * x1;
+        mem::swap(&mut x0, &mut x1);
+        y0 -= q * y1;
+        mem::swap(&mut y0, &mut y1);
+    }
+    assert!(a != i64::min_value() as i128, "gcd doesn't fit in i64");
+    if a < 0 {
+        (-a as i64, -x0 as i64, -y0 as i64)
+    } else {
+        (a as i64, x0 as i64, y0 as i64)
+    }
+}
+
+/// Returns `y` in `[0, m)` such that `a * y == 1 (mod m)`.
+/// Returns `None` if `a` and `m` are not coprime.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::inv_mod;
+/// assert_eq!(Some(7), inv_mod(3, 10));
+/// assert_eq!(Some(3), inv_mod(-3, 10));
+/// assert_eq!(None, inv_mod(4, 10));
+/// ```
+pub fn inv_mod(a: i64, m: u64) -> Option<u64> {
+    assert!(m >= 1);
+    let (g, x) = inv_gcd((a as i128).rem_euclid(m as i128), m as i128);
+    if g == 1 {
+        Some(x as u64)
+    } else {
+        None
+    }
+}
+
+/// Returns `(g, x)` where `g = gcd(a, m)` and `a * x == g (mod m)`, `0 <= x < m / g`.
+/// `a` should be in `[0, m)`.
+fn inv_gcd(a: i128, m: i128) -> (i128, i128) {
+    if a == 0 {
+        return (m, 0);
+    }
+    let (mut s, mut t) = (m, a);
+    let (mut m0, mut m1) = (0i128, 1i128);
+    while t != 0 {
+        let u = s / t;
+        s -= t * u;
+        m0 -= m1 * u;
+        mem::swap(&mut s, &mut t);
+        mem::swap(&mut m0, &mut m1);
+    }
+    if m0 < 0 {
+        m0 += m / s;
+    }
+    (s, m0)
+}
+
+/// Chinese remainder theorem.
+///
+/// Returns `(x, l)` such that `x == r[i] (mod m[i])` for all `i`, where `l = lcm(m)` and `0 <= x < l`.
+/// The moduli don't have to be coprime. Returns `None` if there is no solution.
+///
+/// # Panics
+/// Panics if `r.len() != m.len()`, any `m[i]` is zero or the lcm doesn't fit in `u64`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::crt;
+/// assert_eq!(Some((23, 105)), crt(&[2, 3, 2], &[3, 5, 7]));
+/// assert_eq!(Some((10, 12)), crt(&[4, 10], &[6, 12]));
+/// assert_eq!(None, crt(&[1, 2], &[4, 6]));
+/// assert_eq!(Some((0, 1)), crt(&[], &[]));
+/// ```
+pub fn crt(r: &[i64], m: &[u64]) -> Option<(u64, u64)> {
+    assert_eq!(r.len(), m.len());
+    let (mut r0, mut m0) = (0i128, 1i128);
+    for (&ri, &mi) in r.iter().zip(m) {
+        assert!(mi >= 1);
+        let mut m1 = mi as i128;
+        let mut r1 = (ri as i128).rem_euclid(m1);
+        if m0 < m1 {
+            mem::swap(&mut r0, &mut r1);
+            mem::swap(&mut m0, &mut m1);
+        }
+        if m0 % m1 == 0 {
+            if r0 % m1 != r1 {
+                return None;
+            }
+            continue;
+        }
+        // x = r0 + m0 * t, where m0 * t == r1 - r0 (mod m1)
+        let (g, im) = inv_gcd(m0, m1);
+        if (r1 - r0) % g != 0 {
+            return None;
+        }
+        let u1 = m1 / g;
+        // check before multiplying, since m0 * u1 may not fit even in i128
+        assert!(
+            m0 <= u64::max_value() as i128 / u1,
+            "lcm of moduli doesn't fit in u64"
+        );
+        let t = ((r1 - r0) / g).rem_euclid(u1) * im % u1;
+        r0 += t * m0;
+        m0 *= u1;
+    }
+    Some((r0 as u64, m0 as u64))
+}
+
+/// Garner's algorithm.
+///
+/// Returns `x % modulo` where `x` is the unique integer in `[0, m[0] * m[1] * ...)`
+/// such that `x == r[i] (mod m[i])` for all `i`. The moduli should be pairwise coprime.
+///
+/// This is useful when the product of moduli is too large to represent.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::garner;
+/// // x = 23 satisfies x == 2 (mod 3), x == 3 (mod 5), x == 2 (mod 7)
+/// assert_eq!(23 % 10, garner(&[2, 3, 2], &[3, 5, 7], 10));
+/// ```
+pub fn garner(r: &[u64], m: &[u64], modulo: u64) -> u64 {
+    assert_eq!(r.len(), m.len());
+    assert!(modulo >= 1);
+    let n = r.len();
+    // x = c[0] + c[1] * m[0] + c[2] * m[0] * m[1] + ...
+    // prod[j] = m[0] * ... * m[i - 1] mod m[j] (prod[n] is for `modulo`)
+    // sum[j] = c[0] + c[1] * m[0] + ... mod m[j]
+    // all values are residues less than 2^64, so products fit in u128
+    let mut mods: Vec<u128> = m.iter().map(|&x| x as u128).collect();
+    mods.push(modulo as u128);
+    let mut prod: Vec<u128> = mods.iter().map(|&mj| 1 % mj).collect();
+    let mut sum = vec![0u128; n + 1];
+    for i in 0..n {
+        let mi = mods[i];
+        let ri = r[i] as u128 % mi;
+        let inv = match inv_gcd(prod[i] as i128, mi as i128) {
+            (1, inv) => inv as u128,
+            _ => panic!("moduli should be pairwise coprime"),
+        };
+        let c = (ri + mi - sum[i]) % mi * inv % mi;
+        for j in i + 1..=n {
+            sum[j] = (sum[j] + c * prod[j]) % mods[j];
+            prod[j] = prod[j] * mi % mods[j];
+        }
+    }
+    sum[n] as u64
+}
+
+/// Returns `sum_{i=0}^{n-1} floor((a * i + b) / m)`.
+///
+/// O(log m)
+///
+/// # Examples
+/// ```
+/// use algorithms::math::floor_sum;
+/// // floor(1/3) + floor(3/3) + floor(5/3) + floor(7/3)
+/// assert_eq!(4, floor_sum(4, 3, 2, 1));
+/// // floor(-1/2) + floor(-2/2) + floor(-3/2)
+/// assert_eq!(-4, floor_sum(3, 2, -1, -1));
+/// ```
+pub fn floor_sum(n: u64, m: u64, a: i64, b: i64) -> i128 {
+    assert!(m >= 1);
+    let n = n as i128;
+    let m = m as i128;
+    let (mut a, mut b) = (a as i128, b as i128);
+    let mut ans = 0i128;
+    if a < 0 {
+        let a2 = a.rem_euclid(m);
+        ans -= n * (n - 1) / 2 * ((a2 - a) / m);
+        a = a2;
+    }
+    if b < 0 {
+        let b2 = b.rem_euclid(m);
+        ans -= n * ((b2 - b) / m);
+        b = b2;
+    }
+    ans + floor_sum_unsigned(n as u128, m as u128, a as u128, b as u128) as i128
+}
+
+fn floor_sum_unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+    let mut ans = 0;
+    loop {
+        if a >= m {
+            ans += n * (n.max(1) - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            ans += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        mem::swap(&mut m, &mut a);
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ext_gcd() {
+        for a in -30..=30 {
+            for b in -30..=30 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert!(g >= 0);
+                assert_eq!(g, a * x + b * y);
+                if g != 0 {
+                    assert_eq!(0, a % g);
+                    assert_eq!(0, b % g);
+                }
+            }
+        }
+        let (g, x, y) = ext_gcd(i64::max_value(), i64::max_value() - 1);
+        assert_eq!(1, g);
+        assert_eq!(
+            1,
+            i64::max_value() as i128 * x as i128 + (i64::max_value() - 1) as i128 * y as i128
+        );
+        assert_eq!((2, 0, 1), ext_gcd(i64::min_value(), 2));
+        let (g, x, y) = ext_gcd(i64::min_value(), 3);
+        assert_eq!(1, g);
+        assert_eq!(1, i64::min_value() as i128 * x as i128 + 3 * y as i128);
+    }
+
+    #[test]
+    #[should_panic(expected = "gcd doesn't fit in i64")]
+    fn test_ext_gcd_min() {
+        ext_gcd(i64::min_value(), 0);
+    }
+
+    #[test]
+    fn test_inv_mod() {
+        for m in 1..50u64 {
+            for a in -50..50i64 {
+                let expected = (0..m)
+                    .find(|&y| (a as i128 * y as i128).rem_euclid(m as i128) == 1 % m as i128);
+                assert_eq!(expected, inv_mod(a, m));
+            }
+        }
+        let m = u64::max_value();
+        let y = inv_mod(2, m).unwrap();
+        assert_eq!(1, (2 * y as u128 % m as u128) as u64);
+    }
+
+    #[test]
+    fn test_crt() {
+        for m0 in 1..20u64 {
+            for m1 in 1..20u64 {
+                for r0 in 0..m0 as i64 {
+                    for r1 in 0..m1 as i64 {
+                        let res = crt(&[r0, r1], &[m0, m1]);
+                        let l = (1..).find(|l| l % m0 == 0 && l % m1 == 0).unwrap();
+                        let x = (0..l).find(|&x| x % m0 == r0 as u64 && x % m1 == r1 as u64);
+                        assert_eq!(x.map(|x| (x, l)), res);
+                    }
+                }
+            }
+        }
+        let big = (1u64 << 62) + 135; // odd
+        assert_eq!(Some((2 * big - 1, 2 * big)), crt(&[-1, 1], &[big, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "lcm of moduli doesn't fit in u64")]
+    fn test_crt_overflow() {
+        crt(&[1, 2], &[18446744073709551557, 18446744073709551533]);
+    }
+
+    #[test]
+    fn test_garner() {
+        let m = [1_000_000_007, 998_244_353, 1_000_000_009];
+        let x: u128 = 123_456_789_012_345_678_901_234_567;
+        let r: Vec<u64> = m.iter().map(|&mi| (x % mi as u128) as u64).collect();
+        let prod = m.iter().map(|&mi| mi as u128).product::<u128>();
+        assert!(x < prod);
+        for &modulo in &[1, 2, 1_000_000_007, u64::max_value()] {
+            assert_eq!((x % modulo as u128) as u64, garner(&r, &m, modulo));
+        }
+
+        // x = 5 + p1 * c where c = (7 - 5) / p1 (mod p2)
+        let (p1, p2) = (18446744073709551557u64, 18446744073709551533u64);
+        let c = 2 * inv_mod((p1 % p2) as i64, p2).unwrap() as u128 % p2 as u128;
+        let modulo = u64::max_value() as u128;
+        let expected = (5 + p1 as u128 % modulo * (c % modulo)) % modulo;
+        assert_eq!(
+            expected as u64,
+            garner(&[5, 7], &[p1, p2], u64::max_value())
+        );
+        assert_eq!(5, garner(&[5, 7], &[p1, p2], p1));
+        assert_eq!(7, garner(&[5, 7], &[p1, p2], p2));
+    }
+
+    #[test]
+    fn test_floor_sum() {
+        for n in 0..20u64 {
+            for m in 1..20u64 {
+                for a in -20..20i64 {
+                    for b in -20..20i64 {
+                        let expected: i128 = (0..n as i64)
+                            .map(|i| ((a * i + b) as f64 / m as f64).floor() as i128)
+                            .sum();
+                        assert_eq!(expected, floor_sum(n, m, a, b));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,513 @@
+//! Formal power series over `Mint`
+//!
+//! Multiplication uses [`convolution`](fn.convolution.html), so the module should be
+//! NTT-friendly such as `MOD_998`.
+// ref: https://opt-cp.com/fps-fast-algorithms/
+#![allow(clippy::suspicious_arithmetic_impl)]
+use super::convolution::convolution;
+use super::mint::{Mint, Module};
+use std::ops;
+
+/// Formal power series `a[0] + a[1] x + a[2] x^2 + ...` (or a polynomial) over `Mint<M>`.
+///
+/// Methods like [`inv`](#method.inv) take the number of terms `n` to compute
+/// and return the series modulo `x^n`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{FormalPowerSeries, Mint998};
+/// // 1 / (1 - x - x^2) is the generating function of Fibonacci numbers
+/// let f = FormalPowerSeries::from(vec![1, -1, -1]);
+/// let g: FormalPowerSeries<_> = f.inv(10);
+/// assert_eq!(Mint998::from(55), g[9]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FormalPowerSeries<M: Module> {
+    coef: Vec<Mint<M>>,
+}
+
+impl<M: Module> FormalPowerSeries<M> {
+    /// Creates a series from coefficients `coef[0] + coef[1] x + coef[2] x^2 + ...`.
+    pub fn new(coef: Vec<Mint<M>>) -> FormalPowerSeries<M> {
+        FormalPowerSeries { coef }
+    }
+
+    /// Returns the number of stored coefficients.
+    pub fn len(&self) -> usize {
+        self.coef.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coef.is_empty()
+    }
+
+    /// Returns coefficients `[a[0], a[1], ...]`.
+    pub fn coef(&self) -> &[Mint<M>] {
+        &self.coef
+    }
+
+    pub fn into_vec(self) -> Vec<Mint<M>> {
+        self.coef
+    }
+
+    /// Returns the coefficient of `x^i`. This is zero if `i >= len()`.
+    pub fn get(&self, i: usize) -> Mint<M> {
+        self.coef.get(i).cloned().unwrap_or_else(|| Mint::from(0))
+    }
+
+    /// Returns the series modulo `x^n`. It is padded with zeros if `n > len()`.
+    pub fn truncate(&self, n: usize) -> FormalPowerSeries<M> {
+        let mut coef: Vec<Mint<M>> = self.coef.iter().take(n).cloned().collect();
+        coef.resize(n, Mint::from(0));
+        FormalPowerSeries { coef }
+    }
+
+    /// Removes trailing zeros so that `len() - 1` is the degree of the polynomial.
+    pub fn shrink(mut self) -> FormalPowerSeries<M> {
+        while self.coef.last().map_or(false, |x| x.val() == 0) {
+            self.coef.pop();
+        }
+        self
+    }
+
+    /// Returns the value of the polynomial at `x`.
+    pub fn evaluate(&self, x: Mint<M>) -> Mint<M> {
+        self.coef
+            .iter()
+            .rev()
+            .fold(Mint::from(0), |acc, &c| acc * x + c)
+    }
+
+    /// Returns `f'` where `f` is the original series.
+    pub fn derivative(&self) -> FormalPowerSeries<M> {
+        let coef = self
+            .coef
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| c * i)
+            .collect();
+        FormalPowerSeries { coef }
+    }
+
+    /// Returns `F` such that `F' = f` and `F(0) = 0` where `f` is the original series.
+    pub fn integral(&self) -> FormalPowerSeries<M> {
+        let mut coef = Vec::with_capacity(self.len() + 1);
+        coef.push(Mint::from(0));
+        let n = self.len();
+        // inv[i] = 1 / i
+        let mut inv: Vec<Mint<M>> = vec![Mint::from(1); n + 1];
+        let p = M::module() as usize;
+        for i in 2..=n {
+            inv[i] = -inv[p % i] * (p / i);
+        }
+        for (i, &c) in self.coef.iter().enumerate() {
+            coef.push(c * inv[i + 1]);
+        }
+        FormalPowerSeries { coef }
+    }
+
+    /// Returns `g` such that `f * g == 1 (mod x^n)` where `f` is the original series.
+    ///
+    /// # Panics
+    /// Panics if the constant term is zero.
+    ///
+    /// O(n log n)
+    pub fn inv(&self, n: usize) -> FormalPowerSeries<M> {
+        assert!(self.get(0).val() != 0, "constant term should be non-zero");
+        let mut g = vec![self.get(0).inv()];
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            // g <- g * (2 - f * g) (mod x^k)
+            let f = self.truncate(k);
+            let mut h = convolution(&f.coef, &g);
+            h.truncate(k);
+            for x in h.iter_mut() {
+                *x = -*x;
+            }
+            h[0] += 2;
+            g = convolution(&g, &h);
+            g.truncate(k);
+        }
+        FormalPowerSeries { coef: g }.truncate(n)
+    }
+
+    /// Returns `log f (mod x^n)` where `f` is the original series.
+    ///
+    /// # Panics
+    /// Panics if the constant term is not one.
+    ///
+    /// O(n log n)
+    pub fn log(&self, n: usize) -> FormalPowerSeries<M> {
+        assert!(self.get(0).val() == 1, "constant term should be one");
+        if n == 0 {
+            return FormalPowerSeries::new(Vec::new());
+        }
+        let d = self.truncate(n).derivative();
+        (d * self.inv(n)).truncate(n - 1).integral()
+    }
+
+    /// Returns `exp f (mod x^n)` where `f` is the original series.
+    ///
+    /// # Panics
+    /// Panics if the constant term is not zero.
+    ///
+    /// O(n log n)
+    pub fn exp(&self, n: usize) -> FormalPowerSeries<M> {
+        assert!(self.get(0).val() == 0, "constant term should be zero");
+        let mut g = FormalPowerSeries::new(vec![Mint::from(1)]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            // g <- g * (1 - log g + f) (mod x^k)
+            let mut h = self.truncate(k) - g.log(k);
+            h.coef[0] += 1;
+            g = (g * h).truncate(k);
+        }
+        g.truncate(n)
+    }
+
+    /// Returns `f^e (mod x^n)` where `f` is the original series.
+    ///
+    /// O(n log n)
+    pub fn pow(&self, e: u64, n: usize) -> FormalPowerSeries<M> {
+        if e == 0 {
+            return FormalPowerSeries::new(vec![Mint::from(1)]).truncate(n);
+        }
+        let i = match self.coef.iter().position(|x| x.val() != 0) {
+            Some(i) => i,
+            None => return FormalPowerSeries::new(Vec::new()).truncate(n),
+        };
+        if i as u128 * e as u128 >= n as u128 {
+            return FormalPowerSeries::new(Vec::new()).truncate(n);
+        }
+        // f = c x^i (1 + g) and f^e = c^e x^(ie) exp(e log (1 + g))
+        let shift = i * e as usize;
+        let c = self.coef[i];
+        let c_inv = c.inv();
+        let g = FormalPowerSeries::new(self.coef[i..].iter().map(|&x| x * c_inv).collect());
+        let l = g.log(n - shift) * Mint::from(e);
+        let ce = c.pow(e);
+        let mut coef = vec![Mint::from(0); shift];
+        coef.extend(l.exp(n - shift).coef.into_iter().map(|x| x * ce));
+        FormalPowerSeries { coef }
+    }
+
+    /// Returns `g` such that `g * g == f (mod x^n)` where `f` is the original series.
+    /// Returns `None` if there is no such series.
+    ///
+    /// This assumes `module()` returns prime number. O(n log n)
+    pub fn sqrt(&self, n: usize) -> Option<FormalPowerSeries<M>> {
+        let i = match self.coef.iter().take(n).position(|x| x.val() != 0) {
+            Some(i) => i,
+            None => return Some(FormalPowerSeries::new(Vec::new()).truncate(n)),
+        };
+        if i % 2 != 0 {
+            return None;
+        }
+        let shift = i / 2;
+        let f = FormalPowerSeries::new(self.coef[i..].to_vec());
+        let m = n - shift;
+        let mut g = FormalPowerSeries::new(vec![f.coef[0].sqrt()?]);
+        let inv2 = Mint::from(2).inv();
+        let mut k = 1;
+        while k < m {
+            k *= 2;
+            // g <- (g + f / g) / 2 (mod x^k)
+            let h = (f.truncate(k) * g.inv(k)).truncate(k);
+            g = (g.truncate(k) + h) * inv2;
+        }
+        let mut coef = vec![Mint::from(0); shift];
+        coef.extend(g.truncate(m).coef);
+        Some(FormalPowerSeries { coef })
+    }
+
+    /// Returns polynomials `(q, r)` such that `f = q * g + r` and `deg r < deg g`
+    /// where `f` is the original polynomial.
+    ///
+    /// # Panics
+    /// Panics if `g` is zero.
+    ///
+    /// O(n log n)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{FormalPowerSeries, Mod998};
+    /// let f = FormalPowerSeries::<Mod998>::from(vec![5, 4, 3, 2, 1]);
+    /// let g = FormalPowerSeries::from(vec![1, 1]);
+    /// let (q, r) = f.div_rem(&g);
+    /// assert_eq!(FormalPowerSeries::from(vec![2, 2, 1, 1]), q);
+    /// assert_eq!(FormalPowerSeries::from(vec![3]), r);
+    /// ```
+    pub fn div_rem(
+        &self,
+        g: &FormalPowerSeries<M>,
+    ) -> (FormalPowerSeries<M>, FormalPowerSeries<M>) {
+        let f = self.clone().shrink();
+        let g = g.clone().shrink();
+        assert!(!g.is_empty(), "division by zero polynomial");
+        if f.len() < g.len() {
+            return (FormalPowerSeries::new(Vec::new()), f);
+        }
+        // reversed polynomials satisfy rev(f) = rev(q) * rev(g) (mod x^(deg f - deg g + 1))
+        let k = f.len() - g.len() + 1;
+        let rf = FormalPowerSeries::new(f.coef.iter().rev().cloned().collect());
+        let rg = FormalPowerSeries::new(g.coef.iter().rev().cloned().collect());
+        let mut q = (rf * rg.inv(k)).truncate(k).coef;
+        q.reverse();
+        let q = FormalPowerSeries::new(q);
+        let r = (f - q.clone() * g.clone()).truncate(g.len() - 1).shrink();
+        (q, r)
+    }
+
+    /// Returns values of the polynomial at each of `xs`.
+    ///
+    /// O(n log^2 n)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{FormalPowerSeries, Mint998};
+    /// let f = FormalPowerSeries::from(vec![1, 2, 3]);
+    /// let xs: Vec<Mint998> = vec![0.into(), 1.into(), 2.into()];
+    /// let ys: Vec<Mint998> = vec![1.into(), 6.into(), 17.into()];
+    /// assert_eq!(ys, f.multipoint_evaluate(&xs));
+    /// ```
+    pub fn multipoint_evaluate(&self, xs: &[Mint<M>]) -> Vec<Mint<M>> {
+        if xs.is_empty() {
+            return Vec::new();
+        }
+        let tree = SubproductTree::new(xs);
+        let mut rem = vec![FormalPowerSeries::new(Vec::new()); tree.size * 2];
+        rem[1] = self.div_rem(&tree.node[1]).1;
+        for i in 2..tree.size + xs.len() {
+            rem[i] = rem[i / 2].div_rem(&tree.node[i]).1;
+        }
+        (0..xs.len()).map(|i| rem[tree.size + i].get(0)).collect()
+    }
+
+    /// Returns the polynomial `f` of degree less than `n` such that `f(xs[i]) == ys[i]`
+    /// where `n = xs.len()`. `xs` should be distinct.
+    ///
+    /// O(n log^2 n)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{FormalPowerSeries, Mint998};
+    /// let xs: Vec<Mint998> = vec![0.into(), 1.into(), 2.into()];
+    /// let ys: Vec<Mint998> = vec![1.into(), 6.into(), 17.into()];
+    /// let f = FormalPowerSeries::interpolate(&xs, &ys);
+    /// assert_eq!(FormalPowerSeries::from(vec![1, 2, 3]), f);
+    /// ```
+    pub fn interpolate(xs: &[Mint<M>], ys: &[Mint<M>]) -> FormalPowerSeries<M> {
+        assert_eq!(xs.len(), ys.len());
+        if xs.is_empty() {
+            return FormalPowerSeries::new(Vec::new());
+        }
+        let tree = SubproductTree::new(xs);
+        // f(x) = sum y_i / P'(x_i) * P(x) / (x - x_i) where P(x) = prod (x - x_i)
+        let d = tree.node[1].derivative().multipoint_evaluate(xs);
+        let mut node = vec![FormalPowerSeries::new(Vec::new()); tree.size * 2];
+        for i in 0..xs.len() {
+            node[tree.size + i] = FormalPowerSeries::new(vec![ys[i] / d[i]]);
+        }
+        for i in (1..tree.size).rev() {
+            let l = node[i * 2].clone() * tree.node[i * 2 + 1].clone();
+            let r = node[i * 2 + 1].clone() * tree.node[i * 2].clone();
+            node[i] = l + r;
+        }
+        node.swap_remove(1).truncate(xs.len())
+    }
+}
+
+/// `node[i]` is the product of `(x - xs[j])` for leaves `j` under the node `i`.
+struct SubproductTree<M: Module> {
+    size: usize,
+    node: Vec<FormalPowerSeries<M>>,
+}
+
+impl<M: Module> SubproductTree<M> {
+    fn new(xs: &[Mint<M>]) -> SubproductTree<M> {
+        let size = xs.len().next_power_of_two();
+        let mut node = vec![FormalPowerSeries::new(vec![Mint::from(1)]); size * 2];
+        for (i, &x) in xs.iter().enumerate() {
+            node[size + i] = FormalPowerSeries::new(vec![-x, Mint::from(1)]);
+        }
+        for i in (1..size).rev() {
+            node[i] = node[i * 2].clone() * node[i * 2 + 1].clone();
+        }
+        SubproductTree { size, node }
+    }
+}
+
+impl<M: Module, T: Into<Mint<M>>> From<Vec<T>> for FormalPowerSeries<M> {
+    fn from(v: Vec<T>) -> FormalPowerSeries<M> {
+        FormalPowerSeries::new(v.into_iter().map(|x| x.into()).collect())
+    }
+}
+
+impl<M: Module> PartialEq for FormalPowerSeries<M> {
+    fn eq(&self, other: &FormalPowerSeries<M>) -> bool {
+        self.coef == other.coef
+    }
+}
+
+impl<M: Module> ops::Index<usize> for FormalPowerSeries<M> {
+    type Output = Mint<M>;
+
+    fn index(&self, i: usize) -> &Mint<M> {
+        &self.coef[i]
+    }
+}
+
+impl<M: Module> ops::IndexMut<usize> for FormalPowerSeries<M> {
+    fn index_mut(&mut self, i: usize) -> &mut Mint<M> {
+        &mut self.coef[i]
+    }
+}
+
+impl<M: Module> ops::Add for FormalPowerSeries<M> {
+    type Output = FormalPowerSeries<M>;
+
+    fn add(self, other: FormalPowerSeries<M>) -> FormalPowerSeries<M> {
+        let (mut a, b) = if self.len() >= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        for (x, y) in a.coef.iter_mut().zip(b.coef) {
+            *x += y;
+        }
+        a
+    }
+}
+
+impl<M: Module> ops::Neg for FormalPowerSeries<M> {
+    type Output = FormalPowerSeries<M>;
+
+    fn neg(mut self) -> FormalPowerSeries<M> {
+        for x in self.coef.iter_mut() {
+            *x = -*x;
+        }
+        self
+    }
+}
+
+impl<M: Module> ops::Sub for FormalPowerSeries<M> {
+    type Output = FormalPowerSeries<M>;
+
+    fn sub(self, other: FormalPowerSeries<M>) -> FormalPowerSeries<M> {
+        self + (-other)
+    }
+}
+
+impl<M: Module> ops::Mul for FormalPowerSeries<M> {
+    type Output = FormalPowerSeries<M>;
+
+    fn mul(self, other: FormalPowerSeries<M>) -> FormalPowerSeries<M> {
+        FormalPowerSeries::new(convolution(&self.coef, &other.coef))
+    }
+}
+
+impl<M: Module> ops::Mul<Mint<M>> for FormalPowerSeries<M> {
+    type Output = FormalPowerSeries<M>;
+
+    fn mul(mut self, other: Mint<M>) -> FormalPowerSeries<M> {
+        for x in self.coef.iter_mut() {
+            *x *= other;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Mint998, Mod998};
+    use crate::util::XorShift;
+
+    type Fps = FormalPowerSeries<Mod998>;
+
+    fn random_fps(rng: &mut XorShift, n: usize) -> Fps {
+        FormalPowerSeries::from((0..n).map(|_| rng.next_u32()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_inv() {
+        let mut rng = XorShift::new();
+        for &n in &[1, 2, 10, 100, 300] {
+            let mut f = random_fps(&mut rng, n);
+            f[0] = Mint998::from(1);
+            let g = f.inv(n);
+            let one = (f * g).truncate(n);
+            assert_eq!(Fps::from(vec![1]).truncate(n), one);
+        }
+    }
+
+    #[test]
+    fn test_exp_log() {
+        let mut rng = XorShift::new();
+        for &n in &[1, 2, 10, 100, 300] {
+            let mut f = random_fps(&mut rng, n);
+            f[0] = Mint998::from(0);
+            let g = f.exp(n);
+            assert_eq!(Mint998::from(1), g[0]);
+            assert_eq!(f, g.log(n));
+        }
+        // exp(x) = sum x^n / n!
+        let e = Fps::from(vec![0, 1]).exp(6);
+        assert_eq!(Mint998::from(120).inv(), e[5]);
+    }
+
+    #[test]
+    fn test_pow_sqrt() {
+        let mut rng = XorShift::new();
+        for &n in &[1, 10, 100] {
+            for &shift in &[0, 1, 3] {
+                let mut coef = vec![Mint998::from(0); shift];
+                coef.extend(random_fps(&mut rng, n).into_vec());
+                let f = Fps::new(coef);
+                let mut expected = Fps::from(vec![1]).truncate(n);
+                for e in 0..5 {
+                    assert_eq!(expected, f.pow(e, n));
+                    expected = (expected * f.clone()).truncate(n);
+                }
+            }
+            let g = random_fps(&mut rng, n) * Fps::from(vec![0, 1]);
+            let sq = (g.clone() * g.clone()).truncate(n + 1);
+            let h = sq.sqrt(n + 1).unwrap();
+            assert_eq!(sq, (h.clone() * h).truncate(n + 1));
+        }
+        assert_eq!(None, Fps::from(vec![0, 1]).sqrt(3));
+        assert_eq!(None, Fps::from(vec![3]).sqrt(3));
+        assert_eq!(Fps::from(vec![0, 0, 0]), Fps::from(vec![0, 1]).pow(3, 3));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng = XorShift::new();
+        for &(n, m) in &[(1, 1), (5, 10), (100, 30), (300, 200)] {
+            let f = random_fps(&mut rng, n).shrink();
+            let g = random_fps(&mut rng, m).shrink();
+            let (q, r) = f.div_rem(&g);
+            assert!(r.len() < g.len());
+            assert_eq!(f, (q * g + r).shrink());
+        }
+    }
+
+    #[test]
+    fn test_multipoint() {
+        let mut rng = XorShift::new();
+        for &n in &[1, 5, 100] {
+            let f = random_fps(&mut rng, n);
+            let xs: Vec<Mint998> = (0..n + 3).map(|_| Mint998::from(rng.next_u32())).collect();
+            let ys = f.multipoint_evaluate(&xs);
+            for (&x, &y) in xs.iter().zip(ys.iter()) {
+                assert_eq!(f.evaluate(x), y);
+            }
+            let xs: Vec<Mint998> = (0..n).map(|i| Mint998::from(i * i + 1)).collect();
+            let ys = f.multipoint_evaluate(&xs);
+            assert_eq!(f, FormalPowerSeries::interpolate(&xs, &ys));
+        }
+    }
+}
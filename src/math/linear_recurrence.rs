@@ -0,0 +1,176 @@
+//! Linear recurrences
+use super::convolution::convolution_any;
+use super::mint::{Mint, Module};
+
+/// Returns the shortest `c` such that `s[i] = c[0] * s[i - 1] + c[1] * s[i - 2] + ... + c[d - 1] * s[i - d]`
+/// for all `i >= d` where `d = c.len()`.
+///
+/// `2d` terms are enough to recover a recurrence of order `d`. The module should be prime.
+///
+/// O(n^2)
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{berlekamp_massey, Mint107};
+/// let s: Vec<Mint107> = vec![0.into(), 1.into(), 1.into(), 2.into(), 3.into(), 5.into()];
+/// assert_eq!(vec![Mint107::from(1), Mint107::from(1)], berlekamp_massey(&s));
+/// ```
+pub fn berlekamp_massey<M: Module>(s: &[Mint<M>]) -> Vec<Mint<M>> {
+    // c and b are connection polynomials with c[0] = b[0] = 1
+    let mut c = vec![Mint::from(1)];
+    let mut b = vec![Mint::from(1)];
+    let mut len = 0;
+    let mut shift = 1;
+    let mut last = Mint::from(1); // discrepancy when b was updated
+    for i in 0..s.len() {
+        let d: Mint<M> = c
+            .iter()
+            .take(len + 1)
+            .enumerate()
+            .map(|(j, &x)| x * s[i - j])
+            .sum();
+        if d.val() == 0 {
+            shift += 1;
+            continue;
+        }
+        let coef = d * last.inv();
+        let prev = c.clone();
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, Mint::from(0));
+        }
+        for (j, &x) in b.iter().enumerate() {
+            c[j + shift] -= coef * x;
+        }
+        if 2 * len <= i {
+            len = i + 1 - len;
+            b = prev;
+            last = d;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+    c.resize(len + 1, Mint::from(0));
+    c[1..].iter().map(|&x| -x).collect()
+}
+
+/// Returns `[x^k] p(x) / q(x)` by Bostan–Mori algorithm. `q[0]` should be invertible.
+///
+/// O(d log d log k) where `d` is the degree of `q`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{bostan_mori, Mint107};
+/// // 1 / (1 - x - x^2) is the generating function of Fibonacci numbers (starting from 1, 1)
+/// let p = vec![Mint107::from(1)];
+/// let q = vec![Mint107::from(1), Mint107::from(-1), Mint107::from(-1)];
+/// assert_eq!(Mint107::from(89), bostan_mori(&p, &q, 10));
+/// ```
+pub fn bostan_mori<M: Module>(p: &[Mint<M>], q: &[Mint<M>], mut k: u64) -> Mint<M> {
+    assert!(!q.is_empty() && q[0].val() != 0);
+    let mut p = p.to_vec();
+    let mut q = q.to_vec();
+    while k > 0 && !p.is_empty() {
+        // p(x) / q(x) = p(x) q(-x) / q(x) q(-x), and the denominator is a polynomial of x^2
+        let q_neg: Vec<Mint<M>> = q
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| if i % 2 == 0 { x } else { -x })
+            .collect();
+        let u = convolution_any(&p, &q_neg);
+        let v = convolution_any(&q, &q_neg);
+        p = u.into_iter().skip((k % 2) as usize).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        k /= 2;
+    }
+    match p.first() {
+        Some(&x) => x * q[0].inv(),
+        None => Mint::from(0),
+    }
+}
+
+/// Returns `a[k]` of the sequence defined by
+/// `a[i] = c[0] * a[i - 1] + c[1] * a[i - 2] + ... + c[d - 1] * a[i - d]`
+/// with the initial terms `a[0..d]`.
+///
+/// `a` may contain more terms than `d`, and they should satisfy the recurrence.
+///
+/// O(d log d log k)
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{kth_term, Mint107};
+/// let a = vec![Mint107::from(0), Mint107::from(1)];
+/// let c = vec![Mint107::from(1), Mint107::from(1)];
+/// // Fibonacci number F(10^18) mod 10^9+7
+/// assert_eq!(Mint107::from(209_783_453), kth_term(&a, &c, 1_000_000_000_000_000_000));
+/// ```
+pub fn kth_term<M: Module>(a: &[Mint<M>], c: &[Mint<M>], k: u64) -> Mint<M> {
+    let d = c.len();
+    assert!(a.len() >= d);
+    if k < a.len() as u64 {
+        return a[k as usize];
+    }
+    if d == 0 {
+        return Mint::from(0);
+    }
+    // q(x) = 1 - c[0] x - c[1] x^2 - ..., p(x) = a(x) q(x) mod x^d
+    let mut q = vec![Mint::from(1)];
+    q.extend(c.iter().map(|&x| -x));
+    let mut p = convolution_any(&a[..d], &q);
+    p.truncate(d);
+    bostan_mori(&p, &q, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Matrix, Mint998};
+    use crate::util::XorShift;
+
+    #[test]
+    fn test_berlekamp_massey() {
+        let mut rng = XorShift::new();
+        for d in 0..20 {
+            let c: Vec<Mint998> = (0..d).map(|_| Mint998::from(rng.next_u32())).collect();
+            let mut a: Vec<Mint998> = (0..d).map(|_| Mint998::from(rng.next_u32())).collect();
+            for i in d..2 * d + 10 {
+                let x = (0..d).map(|j| c[j] * a[i - 1 - j]).sum();
+                a.push(x);
+            }
+            let found = berlekamp_massey(&a);
+            assert!(found.len() <= d);
+            for i in found.len()..a.len() {
+                let x: Mint998 = (0..found.len()).map(|j| found[j] * a[i - 1 - j]).sum();
+                assert_eq!(a[i], x);
+            }
+            let k = 1000;
+            for i in 2 * d + 10..=k {
+                let x = (0..d).map(|j| c[j] * a[i - 1 - j]).sum();
+                a.push(x);
+            }
+            assert_eq!(a[k], kth_term(&a[..d], &c, k as u64));
+            assert_eq!(a[k], kth_term(&a[..found.len()], &found, k as u64));
+        }
+        assert!(berlekamp_massey::<crate::math::Mod998>(&[]).is_empty());
+        let zeros = vec![Mint998::from(0); 10];
+        assert!(berlekamp_massey(&zeros).is_empty());
+    }
+
+    #[test]
+    fn test_kth_term() {
+        // a[i] = 2 a[i - 1], a[0] = 1
+        let a = vec![Mint998::from(1)];
+        let c = vec![Mint998::from(2)];
+        for &k in &[0, 1, 10, 1_000_000_007, 1 << 60] {
+            assert_eq!(Mint998::from(2).pow(k), kth_term(&a, &c, k));
+        }
+        // Fibonacci numbers
+        let m: Matrix<Mint998> = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+        let a = vec![Mint998::from(0), Mint998::from(1), Mint998::from(1)];
+        let c = vec![Mint998::from(1), Mint998::from(1)];
+        for &k in &[0, 2, 3, 1_000_000_000_000_000_000] {
+            assert_eq!(m.pow(k)[0][1], kth_term(&a, &c, k));
+        }
+    }
+}
@@ -0,0 +1,541 @@
+//! Matrix and linear algebra over `Mint`
+//!
+//! Multiplication is generic over [`Semiring`](trait.Semiring.html), so the same code
+//! works for the usual arithmetic ([`AddMul`](struct.AddMul.html)) and
+//! shortest/longest paths ([`MinPlus`](struct.MinPlus.html), [`MaxPlus`](struct.MaxPlus.html)).
+use super::mint::{Mint, Module};
+use std::ops;
+
+/// Operations of a semiring used in matrix multiplication.
+pub trait Semiring<T> {
+    /// Identity of `add`
+    fn zero() -> T;
+    /// Identity of `mul`
+    fn one() -> T;
+    fn add(a: &T, b: &T) -> T;
+    fn mul(a: &T, b: &T) -> T;
+}
+
+/// The usual `(+, *)` semiring.
+pub struct AddMul;
+
+/// `(min, +)` semiring where `max_value()` represents the infinity.
+pub struct MinPlus;
+
+/// `(max, +)` semiring where `min_value()` represents the negative infinity.
+pub struct MaxPlus;
+
+impl<M: Module> Semiring<Mint<M>> for AddMul {
+    fn zero() -> Mint<M> {
+        Mint::from(0)
+    }
+    fn one() -> Mint<M> {
+        Mint::from(1)
+    }
+    fn add(a: &Mint<M>, b: &Mint<M>) -> Mint<M> {
+        *a + *b
+    }
+    fn mul(a: &Mint<M>, b: &Mint<M>) -> Mint<M> {
+        *a * *b
+    }
+}
+
+macro_rules! impl_semiring {
+    ($($t:ty),*) => {
+        $(
+            impl Semiring<$t> for AddMul {
+                fn zero() -> $t {
+                    0
+                }
+                fn one() -> $t {
+                    1
+                }
+                fn add(a: &$t, b: &$t) -> $t {
+                    a + b
+                }
+                fn mul(a: &$t, b: &$t) -> $t {
+                    a * b
+                }
+            }
+
+            impl Semiring<$t> for MinPlus {
+                fn zero() -> $t {
+                    <$t>::max_value()
+                }
+                fn one() -> $t {
+                    0
+                }
+                fn add(a: &$t, b: &$t) -> $t {
+                    *a.min(b)
+                }
+                fn mul(a: &$t, b: &$t) -> $t {
+                    if *a == Self::zero() || *b == Self::zero() {
+                        Self::zero()
+                    } else {
+                        a.saturating_add(*b)
+                    }
+                }
+            }
+
+            impl Semiring<$t> for MaxPlus {
+                fn zero() -> $t {
+                    <$t>::min_value()
+                }
+                fn one() -> $t {
+                    0
+                }
+                fn add(a: &$t, b: &$t) -> $t {
+                    *a.max(b)
+                }
+                fn mul(a: &$t, b: &$t) -> $t {
+                    if *a == Self::zero() || *b == Self::zero() {
+                        Self::zero()
+                    } else {
+                        a.saturating_add(*b)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_semiring!(i32, i64, u32, u64, usize);
+
+/// Two dimensional matrix.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{Matrix, Mint107};
+/// // Fibonacci numbers
+/// let a: Matrix<Mint107> = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+/// let f = a.pow(90);
+/// assert_eq!(Mint107::from(2_880_067_194_370_816_120u64), f[0][1]);
+///
+/// let b: Matrix<Mint107> = Matrix::from(vec![vec![2, 1], vec![1, 1]]);
+/// assert_eq!(Mint107::from(1), b.determinant());
+/// assert_eq!(b.inverse().unwrap(), Matrix::from(vec![vec![1, -1], vec![-1, 2]]));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Matrix<T> {
+    h: usize,
+    w: usize,
+    data: Vec<Vec<T>>,
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Creates a `h` x `w` matrix filled with `value`.
+    pub fn new(h: usize, w: usize, value: T) -> Matrix<T> {
+        Matrix {
+            h,
+            w,
+            data: vec![vec![value; w]; h],
+        }
+    }
+
+    /// Creates a matrix from rows. All rows should have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let h = rows.len();
+        let w = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == w));
+        Matrix { h, w, data: rows }
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let data = (0..self.w)
+            .map(|j| (0..self.h).map(|i| self.data[i][j].clone()).collect())
+            .collect();
+        Matrix {
+            h: self.w,
+            w: self.h,
+            data,
+        }
+    }
+
+    /// Identity matrix of the semiring `S`.
+    pub fn identity_with<S: Semiring<T>>(n: usize) -> Matrix<T> {
+        let mut res = Matrix::new(n, n, S::zero());
+        for i in 0..n {
+            res.data[i][i] = S::one();
+        }
+        res
+    }
+
+    /// Product of matrices over the semiring `S`.
+    ///
+    /// O(h * w * other.w)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{Matrix, MinPlus};
+    /// let inf = i64::max_value();
+    /// let d: Matrix<i64> = Matrix::from(vec![vec![0, 1, inf], vec![inf, 0, 2], vec![5, inf, 0]]);
+    /// let d2 = d.mul_with::<MinPlus>(&d);
+    /// assert_eq!(3, d2[0][2]);
+    /// ```
+    pub fn mul_with<S: Semiring<T>>(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.w, other.h);
+        let mut res = Matrix::new(self.h, other.w, S::zero());
+        for i in 0..self.h {
+            for k in 0..self.w {
+                let a = &self.data[i][k];
+                for j in 0..other.w {
+                    res.data[i][j] = S::add(&res.data[i][j], &S::mul(a, &other.data[k][j]));
+                }
+            }
+        }
+        res
+    }
+
+    /// `self^e` over the semiring `S`. The matrix should be square.
+    ///
+    /// O(n^3 log e)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{Matrix, MaxPlus};
+    /// let ninf = i64::min_value();
+    /// // the heaviest walk with exactly 3 edges
+    /// let g: Matrix<i64> = Matrix::from(vec![vec![ninf, 3], vec![1, 2]]);
+    /// assert_eq!(7, g.pow_with::<MaxPlus>(3)[0][1]);
+    /// ```
+    pub fn pow_with<S: Semiring<T>>(&self, mut e: u64) -> Matrix<T> {
+        assert_eq!(self.h, self.w);
+        let mut res = Matrix::identity_with::<S>(self.h);
+        let mut a = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                res = res.mul_with::<S>(&a);
+            }
+            e >>= 1;
+            if e > 0 {
+                a = a.mul_with::<S>(&a);
+            }
+        }
+        res
+    }
+}
+
+impl<T: Clone> Matrix<T>
+where
+    AddMul: Semiring<T>,
+{
+    /// Identity matrix.
+    pub fn identity(n: usize) -> Matrix<T> {
+        Matrix::identity_with::<AddMul>(n)
+    }
+
+    /// `self^e`. The matrix should be square.
+    ///
+    /// O(n^3 log e)
+    pub fn pow(&self, e: u64) -> Matrix<T> {
+        self.pow_with::<AddMul>(e)
+    }
+}
+
+impl<M: Module> Matrix<Mint<M>> {
+    /// Transforms the first `cols` columns into the reduced row echelon form.
+    ///
+    /// Returns pivot columns and the factor that the determinant was multiplied by.
+    fn row_reduce(&mut self, cols: usize) -> (Vec<usize>, Mint<M>) {
+        let mut pivots = Vec::new();
+        let mut factor = Mint::from(1);
+        for c in 0..cols {
+            let r = pivots.len();
+            let p = match (r..self.h).find(|&i| self.data[i][c].val() != 0) {
+                Some(p) => p,
+                None => continue,
+            };
+            if p != r {
+                self.data.swap(p, r);
+                factor = -factor;
+            }
+            let inv = self.data[r][c].inv();
+            factor *= inv;
+            for x in &mut self.data[r] {
+                *x *= inv;
+            }
+            for i in 0..self.h {
+                let f = self.data[i][c];
+                if i == r || f.val() == 0 {
+                    continue;
+                }
+                for j in c..self.w {
+                    let y = self.data[r][j] * f;
+                    self.data[i][j] -= y;
+                }
+            }
+            pivots.push(c);
+        }
+        (pivots, factor)
+    }
+
+    /// Determinant of a square matrix.
+    ///
+    /// O(n^3)
+    pub fn determinant(&self) -> Mint<M> {
+        assert_eq!(self.h, self.w);
+        let mut a = self.clone();
+        let (pivots, factor) = a.row_reduce(self.w);
+        if pivots.len() == self.h {
+            factor.inv()
+        } else {
+            Mint::from(0)
+        }
+    }
+
+    /// O(h * w * min(h, w))
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce(self.w).0.len()
+    }
+
+    /// Inverse matrix of a square matrix. Returns `None` if it's singular.
+    ///
+    /// O(n^3)
+    pub fn inverse(&self) -> Option<Matrix<Mint<M>>> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut a = Matrix::new(n, 2 * n, Mint::from(0));
+        for i in 0..n {
+            a.data[i][..n].copy_from_slice(&self.data[i]);
+            a.data[i][n + i] = Mint::from(1);
+        }
+        if a.row_reduce(n).0.len() < n {
+            return None;
+        }
+        let data = a.data.into_iter().map(|row| row[n..].to_vec()).collect();
+        Some(Matrix { h: n, w: n, data })
+    }
+
+    /// Solves `self * x = b`.
+    ///
+    /// Returns one of solutions and a basis of the kernel
+    /// (all solutions are the sums of the solution and linear combinations of the basis),
+    /// or `None` if there is no solution.
+    ///
+    /// O(h * w * min(h, w))
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{Matrix, Mint107};
+    /// let a: Matrix<Mint107> = Matrix::from(vec![vec![1, 2, 3], vec![2, 4, 7]]);
+    /// let b: Vec<Mint107> = vec![1.into(), 3.into()];
+    /// let (x, kernel) = a.solve(&b).unwrap();
+    /// assert_eq!(b, a.apply(&x));
+    /// assert_eq!(1, kernel.len());
+    /// assert_eq!(vec![Mint107::from(0); 2], a.apply(&kernel[0]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn solve(&self, b: &[Mint<M>]) -> Option<(Vec<Mint<M>>, Vec<Vec<Mint<M>>>)> {
+        assert_eq!(self.h, b.len());
+        let w = self.w;
+        let rows = self
+            .data
+            .iter()
+            .zip(b)
+            .map(|(row, &bi)| {
+                let mut row = row.clone();
+                row.push(bi);
+                row
+            })
+            .collect();
+        let mut a = Matrix::from_rows(rows);
+        let (pivots, _) = a.row_reduce(w);
+        if a.data[pivots.len()..].iter().any(|row| row[w].val() != 0) {
+            return None;
+        }
+        let mut x = vec![Mint::from(0); w];
+        for (r, &c) in pivots.iter().enumerate() {
+            x[c] = a.data[r][w];
+        }
+        let mut is_pivot = vec![false; w];
+        for &c in &pivots {
+            is_pivot[c] = true;
+        }
+        let mut kernel = Vec::new();
+        for free in (0..w).filter(|&c| !is_pivot[c]) {
+            let mut v = vec![Mint::from(0); w];
+            v[free] = Mint::from(1);
+            for (r, &c) in pivots.iter().enumerate() {
+                v[c] = -a.data[r][free];
+            }
+            kernel.push(v);
+        }
+        Some((x, kernel))
+    }
+
+    /// Returns `self * v`.
+    pub fn apply(&self, v: &[Mint<M>]) -> Vec<Mint<M>> {
+        assert_eq!(self.w, v.len());
+        self.data
+            .iter()
+            .map(|row| row.iter().zip(v).map(|(&a, &b)| a * b).sum())
+            .collect()
+    }
+}
+
+impl<T: Clone, U: Into<T>> From<Vec<Vec<U>>> for Matrix<T> {
+    fn from(rows: Vec<Vec<U>>) -> Matrix<T> {
+        Matrix::from_rows(
+            rows.into_iter()
+                .map(|r| r.into_iter().map(|x| x.into()).collect())
+                .collect(),
+        )
+    }
+}
+
+impl<T> ops::Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    fn index(&self, i: usize) -> &[T] {
+        &self.data[i]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for Matrix<T> {
+    fn index_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.data[i]
+    }
+}
+
+impl<T: Clone> ops::Mul for &Matrix<T>
+where
+    AddMul: Semiring<T>,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.mul_with::<AddMul>(other)
+    }
+}
+
+impl<T: Clone> ops::Mul for Matrix<T>
+where
+    AddMul: Semiring<T>,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        self.mul_with::<AddMul>(&other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Mint998, Mod998};
+    use crate::util::XorShift;
+
+    fn random_matrix(rng: &mut XorShift, h: usize, w: usize, m: u32) -> Matrix<Mint998> {
+        let mut a = Matrix::new(h, w, Mint998::from(0));
+        for i in 0..h {
+            for j in 0..w {
+                a[i][j] = Mint998::from(rng.next_u32() % m);
+            }
+        }
+        a
+    }
+
+    #[test]
+    fn test_inverse() {
+        let mut rng = XorShift::new();
+        for n in 1..10 {
+            let a = random_matrix(&mut rng, n, n, 998_244_353);
+            let inv = a.inverse().unwrap();
+            assert_eq!(Matrix::identity(n), &a * &inv);
+            assert_eq!(Matrix::identity(n), &inv * &a);
+            assert_eq!(Mint998::from(1), a.determinant() * inv.determinant());
+        }
+        let singular: Matrix<Mint998> = Matrix::from(vec![vec![1, 2], vec![2, 4]]);
+        assert_eq!(None, singular.inverse());
+        assert_eq!(Mint998::from(0), singular.determinant());
+        assert_eq!(1, singular.rank());
+    }
+
+    #[test]
+    fn test_determinant() {
+        // permutation expansion for 3x3
+        let mut rng = XorShift::new();
+        for _ in 0..100 {
+            let a = random_matrix(&mut rng, 3, 3, 3);
+            let mut det = Mint998::from(0);
+            for &(p, sign) in &[
+                ([0, 1, 2], 1),
+                ([1, 2, 0], 1),
+                ([2, 0, 1], 1),
+                ([0, 2, 1], -1),
+                ([2, 1, 0], -1),
+                ([1, 0, 2], -1),
+            ] {
+                det += a[0][p[0]] * a[1][p[1]] * a[2][p[2]] * sign;
+            }
+            assert_eq!(det, a.determinant());
+            assert_eq!(det.val() != 0, a.rank() == 3);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut rng = XorShift::new();
+        for _ in 0..100 {
+            let h = rng.next_u32() as usize % 5 + 1;
+            let w = rng.next_u32() as usize % 5 + 1;
+            let a = random_matrix(&mut rng, h, w, 3);
+            let x: Vec<Mint998> = (0..w).map(|_| Mint998::from(rng.next_u32())).collect();
+            let b = a.apply(&x);
+            let (y, kernel) = a.solve(&b).unwrap();
+            assert_eq!(b, a.apply(&y));
+            assert_eq!(w - a.rank(), kernel.len());
+            for v in &kernel {
+                assert_eq!(vec![Mint998::from(0); h], a.apply(v));
+            }
+        }
+        let a: Matrix<Mint998> = Matrix::from(vec![vec![1, 1], vec![2, 2]]);
+        assert_eq!(None, a.solve(&[1.into(), 1.into()]));
+    }
+
+    #[test]
+    fn test_semiring() {
+        let mut rng = XorShift::new();
+        let n = 6;
+        let inf = u64::max_value();
+        let mut d = Matrix::new(n, n, inf);
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    d[i][j] = 0;
+                } else if rng.next_u32() % 2 == 0 {
+                    d[i][j] = rng.next_u32() as u64 % 100;
+                }
+            }
+        }
+        // Floyd-Warshall
+        let mut f = d.clone();
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    f[i][j] = MinPlus::add(&f[i][j], &MinPlus::mul(&f[i][k], &f[k][j]));
+                }
+            }
+        }
+        assert_eq!(f, d.pow_with::<MinPlus>(n as u64));
+        assert_eq!(
+            Matrix::identity_with::<MinPlus>(n),
+            d.pow_with::<MinPlus>(0)
+        );
+
+        // the number of walks
+        let g: Matrix<u64> = Matrix::from(vec![vec![0u64, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]);
+        assert_eq!(vec![2, 3, 3], g.pow(3)[0].to_vec());
+        let m: Matrix<Mint<Mod998>> =
+            Matrix::from(vec![vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]]);
+        assert_eq!(Mint998::from(3), (m.clone() * m.clone() * m)[0][1]);
+    }
+}
@@ -1,12 +1,94 @@
 #![allow(clippy::suspicious_arithmetic_impl)]
+use super::prime::is_prime;
+use std::cmp;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error;
 use std::fmt;
+use std::hash;
+use std::iter;
 use std::marker::PhantomData;
 use std::mem;
+use std::num::TryFromIntError;
 use std::ops;
+use std::str;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 
 /// Trait for `Mint`. `module()` should return prime number.
 pub trait Module: Copy + Clone {
     fn module() -> u32;
+
+    /// Returns `a * b % module()` where `a` and `b` are less than `module()`.
+    ///
+    /// The default implementation uses `%` operator, which is fast enough
+    /// if `module()` returns a constant because the compiler optimizes the division.
+    /// Override this with [`Barrett`](struct.Barrett.html) if the module is not a constant.
+    #[inline]
+    fn mul(a: u32, b: u32) -> u32 {
+        (a as u64 * b as u64 % Self::module() as u64) as u32
+    }
+}
+
+/// Barrett reduction to compute `a * b % m` without division instructions.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::Barrett;
+/// let b = Barrett::new(1_000_003);
+/// assert_eq!((999_999u64 * 1_000_001 % 1_000_003) as u32, b.mul(999_999, 1_000_001));
+/// ```
+///
+/// It can be used for `Mint` by overriding [`Module::mul`](trait.Module.html#method.mul).
+/// ```
+/// use algorithms::math::{Barrett, Mint, Module};
+/// static BARRETT: Barrett = Barrett::new(1_000_003);
+///
+/// #[derive(Debug, Copy, Clone)]
+/// struct MyMod;
+/// impl Module for MyMod {
+///     fn module() -> u32 {
+///         BARRETT.module()
+///     }
+///     fn mul(a: u32, b: u32) -> u32 {
+///         BARRETT.mul(a, b)
+///     }
+/// }
+/// let x: Mint<MyMod> = 999_999.into();
+/// assert_eq!(16, (x * x).val());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Barrett {
+    m: u32,
+    im: u64, // floor(2^64 / m)
+}
+
+impl Barrett {
+    /// `m` should satisfy `1 <= m < 2^31`.
+    pub const fn new(m: u32) -> Barrett {
+        Barrett {
+            m,
+            im: u64::max_value() / m as u64,
+        }
+    }
+
+    pub fn module(&self) -> u32 {
+        self.m
+    }
+
+    /// Returns `a * b % m` where `a` and `b` are less than `m`.
+    #[inline]
+    pub fn mul(&self, a: u32, b: u32) -> u32 {
+        Barrett::reduce(self.m, self.im, a as u64 * b as u64)
+    }
+
+    #[inline]
+    fn reduce(m: u32, im: u64, z: u64) -> u32 {
+        // `q` is `floor(z / m)` or `floor(z / m) - 1` since `im = floor(2^64 / m)`.
+        let q = ((z as u128 * im as u128) >> 64) as u64;
+        let m = m as u64;
+        let r = z - q * m;
+        (if r >= m { r - m } else { r }) as u32
+    }
 }
 
 /// One of famous numbers in programming contest. `10^9 + 7`
@@ -43,6 +125,48 @@ impl Module for Mod998 {
     }
 }
 
+static DYNAMIC_MODULE: AtomicU32 = AtomicU32::new(MOD_107);
+static DYNAMIC_MODULE_IM: AtomicU64 = AtomicU64::new(Barrett::new(MOD_107).im);
+
+/// struct to implement Module trait with a module number given at runtime.
+///
+/// The module is shared by all threads and it is `MOD_107` until
+/// [`set_module`](#method.set_module) is called.
+/// Values created before changing the module are no longer valid.
+///
+/// Multiplication is done by [`Barrett`](struct.Barrett.html) reduction.
+#[derive(Debug, Copy, Clone)]
+pub struct DynamicMod;
+impl DynamicMod {
+    /// Changes the module number returned by `module()`.
+    ///
+    /// The module and the constant for Barrett reduction are stored separately,
+    /// so this must not be called while other threads are using `DynamicMod`
+    /// (e.g. call it before spawning them). Otherwise they may see the new module
+    /// with the old constant, and multiplication silently returns wrong results.
+    ///
+    /// # Panics
+    /// Panics unless `1 <= m < 2^31`.
+    pub fn set_module(m: u32) {
+        assert!((1..1 << 31).contains(&m));
+        DYNAMIC_MODULE.store(m, Ordering::Relaxed);
+        DYNAMIC_MODULE_IM.store(Barrett::new(m).im, Ordering::Relaxed);
+    }
+}
+impl Module for DynamicMod {
+    #[inline]
+    fn module() -> u32 {
+        DYNAMIC_MODULE.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn mul(a: u32, b: u32) -> u32 {
+        let m = DYNAMIC_MODULE.load(Ordering::Relaxed);
+        let im = DYNAMIC_MODULE_IM.load(Ordering::Relaxed);
+        Barrett::reduce(m, im, a as u64 * b as u64)
+    }
+}
+
 /// Wrapper class to compute mod `1_000_000_007` automatically.
 ///
 /// # Examples
@@ -51,8 +175,7 @@ impl Module for Mod998 {
 /// let x: Mint107 = 1234567.into();
 /// let y: Mint107 = 2345678.into();
 /// let z = x * y;
-/// # // TODO: implement convert to u64
-/// assert_eq!(z.val as u64, 1234567u64 * 2345678u64 % MOD_107 as u64);
+/// assert_eq!(u64::from(z), 1234567u64 * 2345678u64 % MOD_107 as u64);
 /// ```
 ///
 pub type Mint107 = Mint<Mod107>;
@@ -65,7 +188,7 @@ pub type Mint107 = Mint<Mod107>;
 /// let x: Mint109 = 1234567.into();
 /// let y: Mint109 = 2345678.into();
 /// let z = x * y;
-/// assert_eq!(z.val as u64, 1234567u64 * 2345678u64 % MOD_109 as u64);
+/// assert_eq!(u64::from(z), 1234567u64 * 2345678u64 % MOD_109 as u64);
 /// ```
 ///
 pub type Mint109 = Mint<Mod109>;
@@ -78,11 +201,25 @@ pub type Mint109 = Mint<Mod109>;
 /// let x: Mint998 = 1234567.into();
 /// let y: Mint998 = 2345678.into();
 /// let z = x * y;
-/// assert_eq!(z.val as u64, 1234567u64 * 2345678u64 % MOD_998 as u64);
+/// assert_eq!(u64::from(z), 1234567u64 * 2345678u64 % MOD_998 as u64);
 /// ```
 ///
 pub type Mint998 = Mint<Mod998>;
 
+/// Wrapper class to compute mod `m` automatically where `m` is given at runtime.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{DynamicMod, MintDyn};
+/// DynamicMod::set_module(13);
+/// let x: MintDyn = 5.into();
+/// let y: MintDyn = 6.into();
+/// assert_eq!(4, (x * y).val());
+/// assert_eq!(1, (x * x.inv()).val());
+/// ```
+///
+pub type MintDyn = Mint<DynamicMod>;
+
 /// Wrapper class to compute modulo operation.
 /// See examples
 /// [`Mint107`](type.Mint107.html),
@@ -95,12 +232,11 @@ pub type Mint998 = Mint<Mod998>;
 /// let x: Mint107 = 1234567.into();
 /// let y: Mint107 = 2345678.into();
 /// let z = x * y;
-/// assert_eq!(z.val as u64, 1234567u64 * 2345678u64 % MOD_107 as u64);
+/// assert_eq!(u64::from(z), 1234567u64 * 2345678u64 % MOD_107 as u64);
 /// ```
-#[derive(Debug, Copy, Clone, Eq)]
+#[derive(Debug, Copy, Clone)]
 pub struct Mint<M: Module> {
-    /// internal value. this is always less than `self.module()`.
-    pub val: u32,
+    val: u32, // this is always less than `self.module()`
     m: PhantomData<M>,
 }
 
@@ -115,6 +251,11 @@ impl<M: Module> Mint<M> {
             m: PhantomData,
         }
     }
+
+    /// Returns the representative in `[0, module())`.
+    pub fn val(self) -> u32 {
+        self.val
+    }
 }
 
 impl<T: Into<Mint<M>>, M: Module> ops::Add<T> for Mint<M> {
@@ -147,8 +288,7 @@ impl<T: Into<Mint<M>>, M: Module> ops::Mul<T> for Mint<M> {
     type Output = Mint<M>;
 
     fn mul(self, other: T) -> Mint<M> {
-        let nval = self.val as u64 * other.into().val as u64;
-        Mint::new((nval % (self.module() as u64)) as u32)
+        Mint::new(M::mul(self.val, other.into().val))
     }
 }
 
@@ -163,8 +303,13 @@ impl<T: Into<Mint<M>>, M: Module> ops::Div<T> for Mint<M> {
 impl<M: Module> Mint<M> {
     /// Returns number `y` that satisfies `x * y == 1` where `x` is the original value.
     ///
-    /// This assumes `module()` returns prime number.
+    /// This assumes `module()` returns prime number, which is checked in debug builds.
     pub fn inv(self) -> Mint<M> {
+        debug_assert!(
+            is_prime(M::module() as u64),
+            "module {} is not prime",
+            M::module()
+        );
         let mut a = self.val as i32;
         let mut b = self.module() as i32;
         let mut u = 1_i32;
@@ -178,6 +323,191 @@ impl<M: Module> Mint<M> {
         }
         Mint::new(if u < 0 { u + self.module() as i32 } else { u } as u32)
     }
+
+    /// Returns `x^e` where `x` is the original value.
+    ///
+    /// O(log e)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::Mint107;
+    /// assert_eq!(Mint107::from(1024), Mint107::from(2).pow(10));
+    /// assert_eq!(Mint107::from(1), Mint107::from(0).pow(0));
+    /// ```
+    pub fn pow(self, mut e: u64) -> Mint<M> {
+        let mut res = Mint::new(1 % M::module());
+        let mut base = self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        res
+    }
+
+    /// Returns `y` such that `y * y == x` where `x` is the original value.
+    /// The smaller one of two solutions is returned.
+    /// Returns `None` if there is no such number.
+    ///
+    /// This assumes `module()` returns prime number. O(log^2 p) (Tonelli-Shanks algorithm)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::Mint998;
+    /// assert_eq!(Some(Mint998::from(3)), Mint998::from(9).sqrt());
+    /// assert_eq!(None, Mint998::from(3).sqrt());
+    /// ```
+    pub fn sqrt(self) -> Option<Mint<M>> {
+        let p = M::module() as u64;
+        if self.val == 0 || p == 2 {
+            return Some(self);
+        }
+        if self.pow((p - 1) / 2).val != 1 {
+            return None;
+        }
+        // p - 1 = q * 2^s where q is odd
+        let s = (p - 1).trailing_zeros();
+        let q = (p - 1) >> s;
+        let mut z = Mint::<M>::from(2);
+        while z.pow((p - 1) / 2).val == 1 {
+            z += 1;
+        }
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow((q + 1) / 2);
+        while t.val != 1 {
+            // find the least i such that t^(2^i) == 1
+            let mut i = 0;
+            let mut t2 = t;
+            while t2.val != 1 {
+                t2 *= t2;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+        let other = Mint::new(0) - r;
+        Some(if r.val <= other.val { r } else { other })
+    }
+
+    /// Returns the smallest `k >= 0` such that `base^k == x` where `x` is the original value.
+    /// Returns `None` if there is no such number.
+    ///
+    /// This assumes `module()` returns prime number. O(sqrt(p)) (baby-step giant-step algorithm)
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::Mint107;
+    /// let base = Mint107::from(3);
+    /// assert_eq!(Some(12345), base.pow(12345).log(base));
+    /// assert_eq!(None, Mint107::from(0).log(base));
+    /// ```
+    pub fn log(self, base: Mint<M>) -> Option<u64> {
+        let p = M::module() as u64;
+        if self.val == 1 % M::module() {
+            return Some(0);
+        }
+        if base.val == 0 {
+            return if self.val == 0 { Some(1) } else { None };
+        }
+        let mut n = 1;
+        while n * n < p {
+            n += 1;
+        }
+        // baby steps: table[x * base^j] = j (the largest j is kept)
+        let mut table = HashMap::new();
+        let mut cur = self;
+        for j in 0..n {
+            table.insert(cur.val, j);
+            cur *= base;
+        }
+        // giant steps: base^(i * n) == x * base^j
+        let giant = base.pow(n);
+        let mut cur = giant;
+        for i in 1..=n {
+            if let Some(&j) = table.get(&cur.val) {
+                return Some(i * n - j);
+            }
+            cur *= giant;
+        }
+        None
+    }
+
+    /// Returns the smallest primitive root `g` of `module()`,
+    /// i.e. `g^k` for `0 <= k < module() - 1` are all different.
+    ///
+    /// This assumes `module()` returns prime number. O(sqrt(p))
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::{Mint998, Mint107};
+    /// assert_eq!(Mint998::from(3), Mint998::primitive_root());
+    /// assert_eq!(Mint107::from(5), Mint107::primitive_root());
+    /// ```
+    pub fn primitive_root() -> Mint<M> {
+        let p = M::module() as u64;
+        if p == 2 {
+            return Mint::new(1);
+        }
+        let mut factors = Vec::new();
+        let mut x = p - 1;
+        let mut d = 2;
+        while d * d <= x {
+            if x % d == 0 {
+                factors.push(d);
+                while x % d == 0 {
+                    x /= d;
+                }
+            }
+            d += 1;
+        }
+        if x > 1 {
+            factors.push(x);
+        }
+        let mut g = Mint::new(2);
+        while factors.iter().any(|&f| g.pow((p - 1) / f).val == 1) {
+            g += 1;
+        }
+        g
+    }
+}
+
+impl<M: Module> ops::Neg for Mint<M> {
+    type Output = Mint<M>;
+
+    fn neg(self) -> Mint<M> {
+        Mint::new(0) - self
+    }
+}
+
+impl<M: Module> iter::Sum for Mint<M> {
+    fn sum<I: Iterator<Item = Mint<M>>>(iter: I) -> Mint<M> {
+        iter.fold(Mint::new(0), |acc, x| acc + x)
+    }
+}
+
+impl<'a, M: Module> iter::Sum<&'a Mint<M>> for Mint<M> {
+    fn sum<I: Iterator<Item = &'a Mint<M>>>(iter: I) -> Mint<M> {
+        iter.fold(Mint::new(0), |acc, &x| acc + x)
+    }
+}
+
+impl<M: Module> iter::Product for Mint<M> {
+    fn product<I: Iterator<Item = Mint<M>>>(iter: I) -> Mint<M> {
+        iter.fold(Mint::from(1), |acc, x| acc * x)
+    }
+}
+
+impl<'a, M: Module> iter::Product<&'a Mint<M>> for Mint<M> {
+    fn product<I: Iterator<Item = &'a Mint<M>>>(iter: I) -> Mint<M> {
+        iter.fold(Mint::from(1), |acc, &x| acc * x)
+    }
 }
 
 impl<M: Module> PartialEq for Mint<M> {
@@ -192,6 +522,107 @@ impl<M: Module> fmt::Display for Mint<M> {
     }
 }
 
+impl<M: Module> Eq for Mint<M> {}
+
+impl<M: Module> PartialOrd for Mint<M> {
+    fn partial_cmp(&self, other: &Mint<M>) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares representatives in `[0, module())`.
+impl<M: Module> Ord for Mint<M> {
+    fn cmp(&self, other: &Mint<M>) -> cmp::Ordering {
+        self.val.cmp(&other.val)
+    }
+}
+
+impl<M: Module> hash::Hash for Mint<M> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
+impl<M: Module> Default for Mint<M> {
+    fn default() -> Mint<M> {
+        Mint::new(0)
+    }
+}
+
+/// Error returned when parsing a string to `Mint` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMintError;
+
+impl fmt::Display for ParseMintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        "invalid digit found in string".fmt(f)
+    }
+}
+
+impl error::Error for ParseMintError {}
+
+/// Parses a decimal integer of any length with an optional sign, reducing it modulo `module()`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::Mint107;
+/// let x: Mint107 = "100000000000000000000".parse().unwrap();
+/// assert_eq!(Mint107::from(10).pow(20), x);
+/// assert_eq!(Ok(Mint107::from(-7)), "-7".parse());
+/// assert!("1e9".parse::<Mint107>().is_err());
+/// ```
+impl<M: Module> str::FromStr for Mint<M> {
+    type Err = ParseMintError;
+
+    fn from_str(s: &str) -> Result<Mint<M>, ParseMintError> {
+        let (neg, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if digits.is_empty() {
+            return Err(ParseMintError);
+        }
+        let m = M::module() as u64;
+        let mut val = 0;
+        for c in digits.bytes() {
+            if !c.is_ascii_digit() {
+                return Err(ParseMintError);
+            }
+            val = (val * 10 + (c - b'0') as u64) % m;
+        }
+        let x = Mint::new(val as u32);
+        Ok(if neg { -x } else { x })
+    }
+}
+
+macro_rules! impl_from_mint {
+    ($($t:ty)*) => ($(
+        impl<M: Module> From<Mint<M>> for $t {
+            #[inline]
+            fn from(x: Mint<M>) -> $t {
+                x.val.into()
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_try_from_mint {
+    ($($t:ty)*) => ($(
+        impl<M: Module> TryFrom<Mint<M>> for $t {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(x: Mint<M>) -> Result<$t, TryFromIntError> {
+                <$t>::try_from(x.val)
+            }
+        }
+    )*)
+}
+
+impl_from_mint! { u32 u64 i64 u128 i128 }
+impl_try_from_mint! { u8 u16 i8 i16 i32 usize isize }
+
 macro_rules! impl_signed_mint {
     ($($t:ty)*) => ($(
         impl<M: Module> From<$t> for Mint<M> {
@@ -223,6 +654,18 @@ macro_rules! impl_unsigned_mint {
 impl_signed_mint! { i8 i16 i32 i64 isize }
 impl_unsigned_mint! { u8 u16 u32 u64 usize }
 
+impl<M: Module> From<i128> for Mint<M> {
+    fn from(x: i128) -> Mint<M> {
+        Mint::new(x.rem_euclid(M::module() as i128) as u32)
+    }
+}
+
+impl<M: Module> From<u128> for Mint<M> {
+    fn from(x: u128) -> Mint<M> {
+        Mint::new((x % M::module() as u128) as u32)
+    }
+}
+
 impl<T: Into<Mint<M>>, M: Module> ops::AddAssign<T> for Mint<M> {
     fn add_assign(&mut self, other: T) {
         *self = *self + other.into();
@@ -257,4 +700,119 @@ mod tests {
         let b: Mint<Mod107> = Mint::from(1000000000);
         assert_eq!(Mint::from(3000000000u64 % Mod107::module() as u64), a * b);
     }
+
+    #[test]
+    fn test_dynamic() {
+        use super::super::Comb;
+        DynamicMod::set_module(1_000_003);
+        let a = MintDyn::from(1_000_000);
+        let b = MintDyn::from(-4);
+        assert_eq!(MintDyn::from(999_999), a + b * 250_001);
+        assert_eq!(MintDyn::from(1), a * a.inv());
+        let comb: Comb<DynamicMod> = Comb::new(10);
+        assert_eq!(MintDyn::from(252), comb.comb(10, 5));
+    }
+
+    #[test]
+    fn test_barrett() {
+        use crate::util::XorShift;
+        let mut rng = XorShift::new();
+        for &m in &[1, 2, 3, 998_244_353, 1_000_000_007, (1 << 31) - 1] {
+            let b = Barrett::new(m);
+            assert_eq!(0, b.mul(0, 0));
+            assert_eq!(
+                ((m as u64 - 1) * (m as u64 - 1) % m as u64) as u32,
+                b.mul(m - 1, m - 1)
+            );
+            for _ in 0..1000 {
+                let x = rng.next_u32() % m;
+                let y = rng.next_u32() % m;
+                assert_eq!((x as u64 * y as u64 % m as u64) as u32, b.mul(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_sqrt() {
+        for i in 0..1000 {
+            let x = Mint998::from(i);
+            assert_eq!(x * x * x, x.pow(3));
+            let y = (x * x).sqrt().unwrap();
+            assert_eq!(x * x, y * y);
+            assert!(y.val <= (-y).val);
+        }
+        let mut residues = 0;
+        for i in 0..1000 {
+            let x = Mint107::from(i);
+            if let Some(y) = x.sqrt() {
+                assert_eq!(x, y * y);
+                residues += 1;
+            } else {
+                assert_eq!(Mint107::from(-1), x.pow((MOD_107 as u64 - 1) / 2));
+            }
+        }
+        assert!(residues > 400 && residues < 600);
+    }
+
+    #[test]
+    fn test_log() {
+        let g = Mint998::primitive_root();
+        for &k in &[0, 1, 2, 1000, 998_244_351] {
+            assert_eq!(Some(k), g.pow(k).log(g));
+        }
+        // 2 has order 2 modulo 3
+        #[derive(Debug, Clone, Copy)]
+        struct Mod3;
+        impl Module for Mod3 {
+            fn module() -> u32 {
+                3
+            }
+        }
+        let two = Mint::<Mod3>::from(2);
+        assert_eq!(Some(1), two.log(two));
+        assert_eq!(Some(0), Mint::<Mod3>::from(1).log(two));
+        assert_eq!(None, Mint::<Mod3>::from(0).log(two));
+        assert_eq!(two, Mint::<Mod3>::primitive_root());
+    }
+
+    #[test]
+    fn test_iter() {
+        let v: Vec<Mint107> = (1..=10).map(Mint107::from).collect();
+        assert_eq!(Mint107::from(55), v.iter().sum());
+        assert_eq!(Mint107::from(3_628_800), v.iter().product());
+        assert_eq!(Mint107::from(-55), -v.into_iter().sum::<Mint107>());
+    }
+
+    #[test]
+    fn test_conversion() {
+        use std::collections::HashSet;
+        let x = Mint107::from(-1);
+        assert_eq!(MOD_107 - 1, x.val());
+        assert_eq!(MOD_107 - 1, u32::from(x));
+        assert_eq!(MOD_107 as u64 - 1, u64::from(x));
+        assert_eq!(MOD_107 as i64 - 1, i64::from(x));
+        assert_eq!(Ok(MOD_107 as i32 - 1), i32::try_from(x));
+        assert!(u16::try_from(x).is_err());
+        assert_eq!(Ok(255), u8::try_from(Mint107::from(255)));
+        assert_eq!(Mint107::from(0), Mint107::default());
+        assert!(Mint107::from(3) < Mint107::from(-3));
+        let set: HashSet<Mint107> = (0..10).map(|i| Mint107::from(i % 3)).collect();
+        assert_eq!(3, set.len());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let s = "12345678901234567890123456789";
+        let mut expected = Mint998::from(0);
+        for c in s.bytes() {
+            expected = expected * 10 + (c - b'0');
+        }
+        assert_eq!(Ok(expected), s.parse());
+        assert_eq!(Ok(-expected), format!("-{}", s).parse());
+        assert_eq!(Ok(Mint998::from(0)), "-0".parse());
+        assert_eq!(Ok(Mint998::from(5)), "+5".parse());
+        for &bad in &["", "-", "+", "12a", " 1", "--1", "1.0"] {
+            assert_eq!(Err(ParseMintError), bad.parse::<Mint998>());
+        }
+    }
 }
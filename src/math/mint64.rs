@@ -0,0 +1,424 @@
+//! `Mint` with a 64-bit module number
+#![allow(clippy::suspicious_arithmetic_impl)]
+use super::mint::ParseMintError;
+use super::prime::is_prime;
+use std::cmp;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash;
+use std::iter;
+use std::marker::PhantomData;
+use std::mem;
+use std::num::TryFromIntError;
+use std::ops;
+use std::str;
+
+/// Trait for `Mint64`. `module()` should return prime number.
+pub trait Module64: Copy + Clone {
+    fn module() -> u64;
+
+    /// Returns `a * b % module()` where `a` and `b` are less than `module()`.
+    ///
+    /// The default implementation computes the product in `u128`.
+    #[inline]
+    fn mul(a: u64, b: u64) -> u64 {
+        (a as u128 * b as u128 % Self::module() as u128) as u64
+    }
+}
+
+/// Mersenne prime `2^61 - 1`, which is often used for rolling hashes.
+pub const MOD_61: u64 = (1 << 61) - 1;
+
+/// struct to implement Module64 trait. it returns `MOD_61`.
+///
+/// Multiplication is done by shifts instead of division.
+#[derive(Debug, Copy, Clone)]
+pub struct Mod61;
+impl Module64 for Mod61 {
+    fn module() -> u64 {
+        MOD_61
+    }
+
+    #[inline]
+    fn mul(a: u64, b: u64) -> u64 {
+        let z = a as u128 * b as u128;
+        // 2^61 == 1 (mod 2^61 - 1)
+        let x = (z >> 61) as u64 + (z as u64 & MOD_61);
+        if x >= MOD_61 {
+            x - MOD_61
+        } else {
+            x
+        }
+    }
+}
+
+/// Wrapper class to compute mod `2^61 - 1` automatically.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{Mint61, MOD_61};
+/// let x: Mint61 = 1_000_000_000_000u64.into();
+/// let y: Mint61 = 2_000_000_000_000u64.into();
+/// assert_eq!(u64::from(x * y), (1_000_000_000_000u128 * 2_000_000_000_000 % MOD_61 as u128) as u64);
+/// ```
+pub type Mint61 = Mint64<Mod61>;
+
+/// Wrapper class to compute modulo operation for a module less than `2^64`.
+///
+/// This has the same interface as [`Mint`](struct.Mint.html).
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{Mint64, Module64};
+/// #[derive(Debug, Copy, Clone)]
+/// struct MyMod;
+/// impl Module64 for MyMod {
+///     fn module() -> u64 {
+///         1_000_000_000_000_000_003
+///     }
+/// }
+/// let x: Mint64<MyMod> = 1_000_000_000_000_000_002u64.into();
+/// assert_eq!(Mint64::from(1), x * x);
+/// assert_eq!(x, x.inv());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Mint64<M: Module64> {
+    val: u64, // this is always less than `M::module()`
+    m: PhantomData<M>,
+}
+
+impl<M: Module64> Mint64<M> {
+    fn new(val: u64) -> Mint64<M> {
+        assert!(val < M::module());
+        Mint64 {
+            val,
+            m: PhantomData,
+        }
+    }
+
+    /// Returns the representative in `[0, module())`.
+    pub fn val(self) -> u64 {
+        self.val
+    }
+
+    /// Returns number `y` that satisfies `x * y == 1` where `x` is the original value.
+    ///
+    /// This assumes `module()` returns prime number, which is checked in debug builds.
+    pub fn inv(self) -> Mint64<M> {
+        debug_assert!(is_prime(M::module()), "module {} is not prime", M::module());
+        let mut a = self.val as i128;
+        let mut b = M::module() as i128;
+        let mut u = 1_i128;
+        let mut v = 0_i128;
+        while b != 0 {
+            let quo = a / b;
+            a -= quo * b;
+            mem::swap(&mut a, &mut b);
+            u -= quo * v;
+            mem::swap(&mut u, &mut v);
+        }
+        Mint64::new(if u < 0 { u + M::module() as i128 } else { u } as u64)
+    }
+
+    /// Returns `x^e` where `x` is the original value.
+    ///
+    /// O(log e)
+    pub fn pow(self, mut e: u64) -> Mint64<M> {
+        let mut res = Mint64::new(1 % M::module());
+        let mut base = self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        res
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::Add<T> for Mint64<M> {
+    type Output = Mint64<M>;
+
+    fn add(self, other: T) -> Mint64<M> {
+        let (a, b, m) = (self.val, other.into().val, M::module());
+        // `a + b` may overflow
+        Mint64::new(if a >= m - b { a - (m - b) } else { a + b })
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::Sub<T> for Mint64<M> {
+    type Output = Mint64<M>;
+
+    fn sub(self, other: T) -> Mint64<M> {
+        let (a, b, m) = (self.val, other.into().val, M::module());
+        Mint64::new(if a >= b { a - b } else { a + (m - b) })
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::Mul<T> for Mint64<M> {
+    type Output = Mint64<M>;
+
+    fn mul(self, other: T) -> Mint64<M> {
+        Mint64::new(M::mul(self.val, other.into().val))
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::Div<T> for Mint64<M> {
+    type Output = Mint64<M>;
+
+    fn div(self, other: T) -> Mint64<M> {
+        self * other.into().inv()
+    }
+}
+
+impl<M: Module64> ops::Neg for Mint64<M> {
+    type Output = Mint64<M>;
+
+    fn neg(self) -> Mint64<M> {
+        Mint64::new(0) - self
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::AddAssign<T> for Mint64<M> {
+    fn add_assign(&mut self, other: T) {
+        *self = *self + other.into();
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::SubAssign<T> for Mint64<M> {
+    fn sub_assign(&mut self, other: T) {
+        *self = *self - other.into();
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::MulAssign<T> for Mint64<M> {
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other.into();
+    }
+}
+
+impl<T: Into<Mint64<M>>, M: Module64> ops::DivAssign<T> for Mint64<M> {
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other.into();
+    }
+}
+
+impl<M: Module64> iter::Sum for Mint64<M> {
+    fn sum<I: Iterator<Item = Mint64<M>>>(iter: I) -> Mint64<M> {
+        iter.fold(Mint64::new(0), |acc, x| acc + x)
+    }
+}
+
+impl<'a, M: Module64> iter::Sum<&'a Mint64<M>> for Mint64<M> {
+    fn sum<I: Iterator<Item = &'a Mint64<M>>>(iter: I) -> Mint64<M> {
+        iter.fold(Mint64::new(0), |acc, &x| acc + x)
+    }
+}
+
+impl<M: Module64> iter::Product for Mint64<M> {
+    fn product<I: Iterator<Item = Mint64<M>>>(iter: I) -> Mint64<M> {
+        iter.fold(Mint64::from(1), |acc, x| acc * x)
+    }
+}
+
+impl<'a, M: Module64> iter::Product<&'a Mint64<M>> for Mint64<M> {
+    fn product<I: Iterator<Item = &'a Mint64<M>>>(iter: I) -> Mint64<M> {
+        iter.fold(Mint64::from(1), |acc, &x| acc * x)
+    }
+}
+
+impl<M: Module64> PartialEq for Mint64<M> {
+    fn eq(&self, other: &Mint64<M>) -> bool {
+        self.val == other.val
+    }
+}
+
+impl<M: Module64> Eq for Mint64<M> {}
+
+impl<M: Module64> PartialOrd for Mint64<M> {
+    fn partial_cmp(&self, other: &Mint64<M>) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares representatives in `[0, module())`.
+impl<M: Module64> Ord for Mint64<M> {
+    fn cmp(&self, other: &Mint64<M>) -> cmp::Ordering {
+        self.val.cmp(&other.val)
+    }
+}
+
+impl<M: Module64> hash::Hash for Mint64<M> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
+impl<M: Module64> Default for Mint64<M> {
+    fn default() -> Mint64<M> {
+        Mint64::new(0)
+    }
+}
+
+impl<M: Module64> fmt::Display for Mint64<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+/// Parses a decimal integer of any length with an optional sign, reducing it modulo `module()`.
+impl<M: Module64> str::FromStr for Mint64<M> {
+    type Err = ParseMintError;
+
+    fn from_str(s: &str) -> Result<Mint64<M>, ParseMintError> {
+        let (neg, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if digits.is_empty() {
+            return Err(ParseMintError);
+        }
+        let mut x = Mint64::new(0);
+        for c in digits.bytes() {
+            if !c.is_ascii_digit() {
+                return Err(ParseMintError);
+            }
+            x = x * 10 + (c - b'0');
+        }
+        Ok(if neg { -x } else { x })
+    }
+}
+
+macro_rules! impl_signed_mint64 {
+    ($($t:ty)*) => ($(
+        impl<M: Module64> From<$t> for Mint64<M> {
+            #[inline]
+            fn from(x: $t) -> Mint64<M> {
+                Mint64::new((x as i128).rem_euclid(M::module() as i128) as u64)
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_unsigned_mint64 {
+    ($($t:ty)*) => ($(
+        impl<M: Module64> From<$t> for Mint64<M> {
+            #[inline]
+            fn from(x: $t) -> Mint64<M> {
+                Mint64::new((x as u128 % M::module() as u128) as u64)
+            }
+        }
+    )*)
+}
+
+impl_signed_mint64! { i8 i16 i32 i64 i128 isize }
+impl_unsigned_mint64! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! impl_from_mint64 {
+    ($($t:ty)*) => ($(
+        impl<M: Module64> From<Mint64<M>> for $t {
+            #[inline]
+            fn from(x: Mint64<M>) -> $t {
+                x.val.into()
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_try_from_mint64 {
+    ($($t:ty)*) => ($(
+        impl<M: Module64> TryFrom<Mint64<M>> for $t {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(x: Mint64<M>) -> Result<$t, TryFromIntError> {
+                <$t>::try_from(x.val)
+            }
+        }
+    )*)
+}
+
+impl_from_mint64! { u64 u128 i128 }
+impl_try_from_mint64! { u8 u16 u32 i8 i16 i32 i64 usize isize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    #[derive(Debug, Copy, Clone)]
+    struct BigMod;
+    impl Module64 for BigMod {
+        fn module() -> u64 {
+            18_446_744_073_709_551_557 // the largest prime less than 2^64
+        }
+    }
+
+    fn random_u64(rng: &mut XorShift) -> u64 {
+        (rng.next_u32() as u64) << 32 | rng.next_u32() as u64
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = XorShift::new();
+        let m = BigMod::module() as u128;
+        for _ in 0..1000 {
+            let a = random_u64(&mut rng);
+            let b = random_u64(&mut rng);
+            let x = Mint64::<BigMod>::from(a);
+            let y = Mint64::<BigMod>::from(b);
+            let (a, b) = (a as u128 % m, b as u128 % m);
+            assert_eq!(((a + b) % m) as u64, (x + y).val());
+            assert_eq!(((a + m - b) % m) as u64, (x - y).val());
+            assert_eq!((a * b % m) as u64, (x * y).val());
+            if b != 0 {
+                assert_eq!(x, x / y * y);
+            }
+        }
+        let x = Mint64::<BigMod>::from(-1);
+        assert_eq!(BigMod::module() - 1, x.val());
+        assert_eq!(Mint64::from(1), x * x);
+        assert_eq!(Mint64::from(-2), x + x);
+    }
+
+    #[test]
+    fn test_mod61() {
+        let mut rng = XorShift::new();
+        for _ in 0..1000 {
+            let a = random_u64(&mut rng) % MOD_61;
+            let b = random_u64(&mut rng) % MOD_61;
+            assert_eq!(
+                (a as u128 * b as u128 % MOD_61 as u128) as u64,
+                Mod61::mul(a, b)
+            );
+        }
+        assert_eq!(1, Mod61::mul(MOD_61 - 1, MOD_61 - 1));
+        let x = Mint61::from(3);
+        assert_eq!(Mint61::from(1), x.pow(MOD_61 - 1));
+        assert_eq!(Mint61::from(1), x * x.inv());
+    }
+
+    #[test]
+    fn test_traits() {
+        let v: Vec<Mint61> = (1..=20).map(Mint61::from).collect();
+        assert_eq!(Mint61::from(210), v.iter().sum());
+        assert_eq!(
+            Mint61::from(2_432_902_008_176_640_000u64),
+            v.iter().product()
+        );
+        assert_eq!(Mint61::from(-210), -v.into_iter().sum::<Mint61>());
+        assert_eq!(Mint61::from(0), Mint61::default());
+        assert!(Mint61::from(3) < Mint61::from(-3));
+        assert_eq!(MOD_61 - 1, u64::from(Mint61::from(-1)));
+        assert!(u32::try_from(Mint61::from(-1)).is_err());
+        assert_eq!(Ok(7), i64::try_from(Mint61::from(7)));
+        let s = "123456789012345678901234567890";
+        let expected = (123_456_789_012_345_678_901_234_567_890u128 % MOD_61 as u128) as u64;
+        assert_eq!(Ok(Mint61::from(expected)), s.parse());
+        assert_eq!(Ok(-Mint61::from(expected)), format!("-{}", s).parse());
+        assert_eq!(Err(ParseMintError), "x".parse::<Mint61>());
+        assert_eq!("5", format!("{}", Mint61::from(5)));
+    }
+}
@@ -1,14 +1,47 @@
 //! mathematic related functions and structs
 //!
-//! * [`Comb`](struct.Comb.html) - calculate combinations
+//! * [`BigInt`](struct.BigInt.html), [`BigUint`](struct.BigUint.html) - arbitrary-precision integers
+//! * [`Binomial`](struct.Binomial.html) - binomial coefficients modulo any number
+//! * [`Comb`](struct.Comb.html) - calculate combinations, Catalan, Stirling, Bell and partition numbers
+//! * [`ext_gcd`](fn.ext_gcd.html), [`inv_mod`](fn.inv_mod.html), [`crt`](fn.crt.html), [`garner`](fn.garner.html),
+//!   [`floor_sum`](fn.floor_sum.html) - extended Euclid and related functions for arbitrary modules
+//! * [`convolution`](fn.convolution.html) - convolution by number-theoretic transform
+//! * [`FormalPowerSeries`](struct.FormalPowerSeries.html) - formal power series and polynomials
+//! * [`berlekamp_massey`](fn.berlekamp_massey.html), [`kth_term`](fn.kth_term.html) - linear recurrences
+//! * [`Matrix`](struct.Matrix.html) - matrix with linear algebra and semiring multiplication
 //! * [`Mint`](struct.Mint.html), [`Mint107`](type.Mint107.html), [`Mint109`](type.Mint109.html),
-//! [`Mint998`](type.Mint998.html) - wrapper of integer that automatically call modulo operations
+//!   [`Mint998`](type.Mint998.html) - wrapper of integer that automatically call modulo operations
+//! * [`MintDyn`](type.MintDyn.html) - `Mint` with a module number given at runtime
+//! * [`Mint64`](struct.Mint64.html), [`Mint61`](type.Mint61.html) - `Mint` with a 64-bit module number
+//! * [`Rational`](struct.Rational.html) - exact rational numbers
+//! * [`Sieve`](struct.Sieve.html), [`is_prime`](fn.is_prime.html), [`factorize`](fn.factorize.html) - prime numbers and factorization
 
+mod bigint;
+mod binomial;
 mod comb;
+mod convolution;
+mod euclid;
+mod fps;
+mod linear_recurrence;
+mod matrix;
 mod mint;
+mod mint64;
+mod prime;
+mod rational;
 
+pub use self::bigint::{BigInt, BigUint, ParseBigIntError};
+pub use self::binomial::Binomial;
 pub use self::comb::Comb;
-pub use self::mint::{Mint, Module};
+pub use self::convolution::{convolution, convolution_any, convolution_i64, convolution_u64};
+pub use self::euclid::{crt, ext_gcd, floor_sum, garner, inv_mod};
+pub use self::fps::FormalPowerSeries;
+pub use self::linear_recurrence::{berlekamp_massey, bostan_mori, kth_term};
+pub use self::matrix::{AddMul, Matrix, MaxPlus, MinPlus, Semiring};
+pub use self::mint::{Barrett, DynamicMod, MintDyn};
+pub use self::mint::{Mint, Module, ParseMintError};
 pub use self::mint::{Mint107, Mint109, Mint998};
 pub use self::mint::{Mod107, Mod109, Mod998};
 pub use self::mint::{MOD_107, MOD_109, MOD_998};
+pub use self::mint64::{Mint61, Mint64, Mod61, Module64, MOD_61};
+pub use self::prime::{divisors, factorize, is_prime, Sieve};
+pub use self::rational::{Integer, Rational};
@@ -0,0 +1,340 @@
+//! Prime numbers, factorization and related arithmetic functions
+
+/// Linear sieve to provide prime numbers and multiplicative functions up to `n`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::Sieve;
+/// let sieve = Sieve::new(30);
+/// assert_eq!(&[2, 3, 5, 7, 11, 13, 17, 19, 23, 29], sieve.primes());
+/// assert_eq!(vec![(2, 2), (3, 1)], sieve.factorize(12));
+/// assert_eq!(-1, sieve.mobius(30));
+/// assert_eq!(8, sieve.phi(30));
+/// ```
+pub struct Sieve {
+    spf: Vec<u32>, // spf[x] stores the smallest prime factor of x
+    primes: Vec<u32>,
+    mobius: Vec<i8>,
+    phi: Vec<u32>,
+}
+
+impl Sieve {
+    /// Creates a sieve for integers `0..=n`.
+    ///
+    /// O(n)
+    pub fn new(n: usize) -> Sieve {
+        let mut spf = vec![0; n + 1];
+        let mut primes = Vec::new();
+        let mut mobius = vec![0; n + 1];
+        let mut phi = vec![0; n + 1];
+        if n >= 1 {
+            mobius[1] = 1;
+            phi[1] = 1;
+        }
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+                mobius[i] = -1;
+                phi[i] = i as u32 - 1;
+            }
+            for &p in &primes {
+                let j = i * p as usize;
+                if p > spf[i] || j > n {
+                    break;
+                }
+                spf[j] = p;
+                if p == spf[i] {
+                    mobius[j] = 0;
+                    phi[j] = phi[i] * p;
+                } else {
+                    mobius[j] = -mobius[i];
+                    phi[j] = phi[i] * (p - 1);
+                }
+            }
+        }
+        Sieve {
+            spf,
+            primes,
+            mobius,
+            phi,
+        }
+    }
+
+    /// Returns all prime numbers up to `n` in ascending order.
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    pub fn is_prime(&self, x: usize) -> bool {
+        x >= 2 && self.spf[x] as usize == x
+    }
+
+    /// Returns the smallest prime factor of `x` (`x >= 2`).
+    pub fn smallest_prime_factor(&self, x: usize) -> usize {
+        assert!(x >= 2);
+        self.spf[x] as usize
+    }
+
+    /// Möbius function `μ(x)` (`x >= 1`).
+    pub fn mobius(&self, x: usize) -> i8 {
+        assert!(x >= 1);
+        self.mobius[x]
+    }
+
+    /// Euler's totient function `φ(x)` (`x >= 1`).
+    pub fn phi(&self, x: usize) -> u32 {
+        assert!(x >= 1);
+        self.phi[x]
+    }
+
+    /// Returns pairs of a prime factor and its exponent in ascending order.
+    ///
+    /// O(log x)
+    pub fn factorize(&self, mut x: usize) -> Vec<(usize, u32)> {
+        assert!(x >= 1);
+        let mut res: Vec<(usize, u32)> = Vec::new();
+        while x > 1 {
+            let p = self.spf[x] as usize;
+            match res.last_mut() {
+                Some(last) if last.0 == p => last.1 += 1,
+                _ => res.push((p, 1)),
+            }
+            x /= p;
+        }
+        res
+    }
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut a: u64, mut e: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mul_mod(res, a, m);
+        }
+        a = mul_mod(a, a, m);
+        e >>= 1;
+    }
+    res
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Deterministic Miller-Rabin primality test for `u64`.
+///
+/// O(log n)
+///
+/// # Examples
+/// ```
+/// use algorithms::math::is_prime;
+/// assert!(is_prime(998_244_353));
+/// assert!(!is_prime(1_000_000_007 * 3));
+/// assert!(is_prime(18_446_744_073_709_551_557));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    if n < 64 {
+        return (1u64 << n) & 0x28208a20a08a28ac != 0;
+    }
+    if n % 2 == 0 {
+        return false;
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    // these bases are enough for n < 2^64
+    for &a in &[2, 325, 9375, 28178, 450_775, 9_780_504, 1_795_265_022] {
+        let a = a % n;
+        if a == 0 {
+            continue;
+        }
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut composite = true;
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                composite = false;
+                break;
+            }
+        }
+        if composite {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns a non-trivial factor of a composite number `n` by Pollard's rho algorithm.
+fn pollard_rho(n: u64) -> u64 {
+    if n % 2 == 0 {
+        return 2;
+    }
+    const M: u64 = 128;
+    for c in 1.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let diff = |a: u64, b: u64| if a > b { a - b } else { b - a };
+        let mut x = 0;
+        let mut y = 2;
+        let mut ys = 2;
+        let mut g = 1;
+        let mut q = 1;
+        let mut r = 1;
+        // Brent's cycle detection with multiplying differences by batch
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..M.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, diff(x, y), n);
+                }
+                g = gcd(q, n);
+                k += M;
+            }
+            r *= 2;
+        }
+        if g == n {
+            g = 1;
+            while g == 1 {
+                ys = f(ys);
+                g = gcd(diff(x, ys), n);
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// Returns pairs of a prime factor and its exponent in ascending order.
+///
+/// O(n^(1/4)) expected time by Pollard's rho algorithm.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::factorize;
+/// assert_eq!(vec![(2, 3), (3, 1), (1_000_000_007, 1)], factorize(24_000_000_168));
+/// assert!(factorize(1).is_empty());
+/// ```
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    assert!(n >= 1);
+    let mut primes = Vec::new();
+    let mut stack = vec![n];
+    while let Some(x) = stack.pop() {
+        if x == 1 {
+            continue;
+        }
+        if is_prime(x) {
+            primes.push(x);
+            continue;
+        }
+        let d = pollard_rho(x);
+        stack.push(d);
+        stack.push(x / d);
+    }
+    primes.sort();
+    let mut res: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match res.last_mut() {
+            Some(last) if last.0 == p => last.1 += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
+
+/// Returns all divisors of `n` in ascending order.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::divisors;
+/// assert_eq!(vec![1, 2, 3, 4, 6, 12], divisors(12));
+/// ```
+pub fn divisors(n: u64) -> Vec<u64> {
+    let mut res = vec![1];
+    for (p, e) in factorize(n) {
+        let len = res.len();
+        let mut pk = 1;
+        for _ in 0..e {
+            pk *= p;
+            for i in 0..len {
+                res.push(res[i] * pk);
+            }
+        }
+    }
+    res.sort();
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sieve() {
+        let n = 1000;
+        let sieve = Sieve::new(n);
+        for x in 1..=n {
+            assert_eq!(is_prime(x as u64), sieve.is_prime(x));
+            let f = sieve.factorize(x);
+            let g: Vec<(usize, u32)> = factorize(x as u64)
+                .into_iter()
+                .map(|(p, e)| (p as usize, e))
+                .collect();
+            assert_eq!(g, f);
+            let phi = (1..=x).filter(|&y| gcd(x as u64, y as u64) == 1).count();
+            assert_eq!(phi as u32, sieve.phi(x));
+            let mobius = if f.iter().any(|&(_, e)| e > 1) {
+                0
+            } else if f.len() % 2 == 0 {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(mobius, sieve.mobius(x));
+        }
+        assert_eq!(168, sieve.primes().len());
+        assert!(!Sieve::new(1).is_prime(1));
+        assert!(Sieve::new(0).primes().is_empty());
+    }
+
+    #[test]
+    fn test_is_prime() {
+        // strong pseudoprimes and Carmichael numbers
+        for &n in &[561, 2047, 3_215_031_751, 3_825_123_056_546_413_051] {
+            assert!(!is_prime(n));
+        }
+        assert!(is_prime((1 << 61) - 1));
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+    }
+
+    #[test]
+    fn test_factorize() {
+        let p = 4_294_967_291; // the largest prime less than 2^32
+        let q = 4_294_967_279;
+        assert_eq!(vec![(q, 1), (p, 1)], factorize(p * q));
+        assert_eq!(vec![(p, 2)], factorize(p * p));
+        assert_eq!(vec![(2, 63)], factorize(1 << 63));
+        let n = 999_999_999_999_999_989; // prime
+        assert_eq!(vec![(n, 1)], factorize(n));
+        assert_eq!(vec![1, p, q, p * q].len(), divisors(p * q).len());
+    }
+}
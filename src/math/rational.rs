@@ -0,0 +1,464 @@
+//! Exact rational numbers
+#![allow(clippy::suspicious_arithmetic_impl)]
+use super::mint::{Mint, Module};
+use std::cmp;
+use std::fmt;
+use std::ops;
+
+/// Signed integer types used in [`Rational`](struct.Rational.html).
+pub trait Integer:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::Rem<Output = Self>
+    + ops::Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => ($(
+        impl Integer for $t {
+            fn zero() -> $t {
+                0
+            }
+            fn one() -> $t {
+                1
+            }
+        }
+    )*)
+}
+
+impl_integer! { i32 i64 i128 isize }
+
+fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    if a < T::zero() {
+        -a
+    } else {
+        a
+    }
+}
+
+/// `floor(a / b)` for `b > 0`
+fn div_floor<T: Integer>(a: T, b: T) -> T {
+    let q = a / b;
+    if a % b < T::zero() {
+        q - T::one()
+    } else {
+        q
+    }
+}
+
+/// `a - floor(a / b) * b` in `[0, b)` for `b > 0`
+fn rem_floor<T: Integer>(a: T, b: T) -> T {
+    let r = a % b;
+    if r < T::zero() {
+        r + b
+    } else {
+        r
+    }
+}
+
+/// Rational number `num / den` which is always reduced and `den > 0`.
+///
+/// Operations never overflow as long as numerators and denominators of
+/// inputs and results fit in `T`, except that `a / b + c / d` also needs
+/// `a * (d / g)` and `c * (b / g)` to fit in `T` where `g = gcd(b, d)`.
+///
+/// # Examples
+/// ```
+/// use algorithms::math::{Rational, Mint107};
+/// let x = Rational::new(1i64, 3);
+/// let y = Rational::new(-1i64, 6);
+/// assert_eq!(Rational::new(1, 6), x + y);
+/// assert_eq!(Rational::new(-2, 1), x / y);
+/// assert!(y < x);
+/// assert_eq!(-1, y.floor());
+/// assert_eq!(Mint107::from(3).inv(), x.to_mint());
+/// assert_eq!("-1/6", y.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    num: T,
+    den: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// Creates a reduced rational number `num / den`.
+    ///
+    /// # Panics
+    /// Panics if `den` is zero.
+    pub fn new(num: T, den: T) -> Rational<T> {
+        assert!(den != T::zero(), "denominator is zero");
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        if den < T::zero() {
+            Rational {
+                num: -num,
+                den: -den,
+            }
+        } else {
+            Rational { num, den }
+        }
+    }
+
+    pub fn num(&self) -> T {
+        self.num
+    }
+
+    pub fn den(&self) -> T {
+        self.den
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == T::one()
+    }
+
+    /// Returns `1 / self`.
+    pub fn recip(self) -> Rational<T> {
+        Rational::new(self.den, self.num)
+    }
+
+    pub fn abs(self) -> Rational<T> {
+        if self.num < T::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// The largest integer less than or equal to `self`.
+    pub fn floor(&self) -> T {
+        div_floor(self.num, self.den)
+    }
+
+    /// The smallest integer greater than or equal to `self`.
+    pub fn ceil(&self) -> T {
+        -div_floor(-self.num, self.den)
+    }
+
+    /// Returns `num * den^(-1)` modulo `M`. `den` should not be a multiple of the module.
+    pub fn to_mint<M: Module>(&self) -> Mint<M>
+    where
+        T: Into<Mint<M>>,
+    {
+        self.num.into() / self.den.into()
+    }
+
+    /// Returns the closest rational number whose denominator is at most `max_den`.
+    ///
+    /// If there are two such numbers, the one with the smaller denominator is returned.
+    ///
+    /// O(log den) by continued fractions.
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::math::Rational;
+    /// let pi = Rational::new(3_141_592_653_589_793i64, 1_000_000_000_000_000);
+    /// assert_eq!(Rational::new(22, 7), pi.limit_denominator(10));
+    /// assert_eq!(Rational::new(311, 99), pi.limit_denominator(100));
+    /// assert_eq!(Rational::new(355, 113), pi.limit_denominator(1000));
+    /// ```
+    pub fn limit_denominator(&self, max_den: T) -> Rational<T> {
+        assert!(max_den >= T::one());
+        if self.den <= max_den {
+            return *self;
+        }
+        // convergents p0/q0, p1/q1 of the continued fraction
+        let (mut p0, mut q0, mut p1, mut q1) = (T::zero(), T::one(), T::one(), T::zero());
+        let (mut n, mut d) = (self.num, self.den);
+        // n / d is the complete quotient, i.e. self = (p1 * n / d + p0) / (q1 * n / d + q0)
+        loop {
+            let a = div_floor(n, d);
+            // q0 + a * q1 > max_den
+            if q1 != T::zero() && a > (max_den - q0) / q1 {
+                break;
+            }
+            let q2 = q0 + a * q1;
+            let p2 = p0 + a * p1;
+            p0 = p1;
+            q0 = q1;
+            p1 = p2;
+            q1 = q2;
+            let r = rem_floor(n, d);
+            n = d;
+            d = r;
+        }
+        // the best approximation is either the last convergent p1 / q1
+        // or the semiconvergent (p0 + k * p1) / (q0 + k * q1).
+        // With t = n / d, their distances from self are 1 / (q1 * (q1 * t + q0))
+        // and (t - k) / ((q0 + k * q1) * (q1 * t + q0)), so the convergent is
+        // at least as close iff t - k >= (q0 + k * q1) / q1.
+        let k = (max_den - q0) / q1;
+        if Rational::new(n - k * d, d) >= Rational::new(q0 + k * q1, q1) {
+            Rational::new(p1, q1)
+        } else {
+            Rational::new(p0 + k * p1, q0 + k * q1)
+        }
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(x: T) -> Rational<T> {
+        Rational {
+            num: x,
+            den: T::one(),
+        }
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::Add<U> for Rational<T> {
+    type Output = Rational<T>;
+
+    fn add(self, other: U) -> Rational<T> {
+        let other = other.into();
+        let g = gcd(self.den, other.den);
+        let x = self.num * (other.den / g);
+        let y = other.num * (self.den / g);
+        // num = (x + y) / g2 where g2 = gcd(x + y, g), computed without forming x + y
+        let (rx, ry) = (rem_floor(x, g), rem_floor(y, g));
+        let r = if rx >= g - ry { rx - (g - ry) } else { rx + ry };
+        let g2 = gcd(r, g);
+        // x % g2 + y % g2 is either 0 or g2
+        let carry = if rem_floor(x, g2) == T::zero() {
+            T::zero()
+        } else {
+            T::one()
+        };
+        let num = div_floor(x, g2) + carry + div_floor(y, g2);
+        if num == T::zero() {
+            return Rational::from(num);
+        }
+        Rational {
+            num,
+            den: self.den / g * (other.den / g2),
+        }
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::Sub<U> for Rational<T> {
+    type Output = Rational<T>;
+
+    fn sub(self, other: U) -> Rational<T> {
+        self + -other.into()
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::Mul<U> for Rational<T> {
+    type Output = Rational<T>;
+
+    fn mul(self, other: U) -> Rational<T> {
+        let other = other.into();
+        let g1 = gcd(self.num, other.den);
+        let g2 = gcd(other.num, self.den);
+        Rational {
+            num: (self.num / g1) * (other.num / g2),
+            den: (self.den / g2) * (other.den / g1),
+        }
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::Div<U> for Rational<T> {
+    type Output = Rational<T>;
+
+    fn div(self, other: U) -> Rational<T> {
+        self * other.into().recip()
+    }
+}
+
+impl<T: Integer> ops::Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Rational<T> {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::AddAssign<U> for Rational<T> {
+    fn add_assign(&mut self, other: U) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::SubAssign<U> for Rational<T> {
+    fn sub_assign(&mut self, other: U) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::MulAssign<U> for Rational<T> {
+    fn mul_assign(&mut self, other: U) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Integer, U: Into<Rational<T>>> ops::DivAssign<U> for Rational<T> {
+    fn div_assign(&mut self, other: U) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Rational<T>) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    /// Compares by continued fractions to avoid overflow of `a * d` and `c * b`.
+    fn cmp(&self, other: &Rational<T>) -> cmp::Ordering {
+        let (mut a, mut b) = (self.num, self.den);
+        let (mut c, mut d) = (other.num, other.den);
+        let mut reversed = false;
+        loop {
+            let (q1, q2) = (div_floor(a, b), div_floor(c, d));
+            if q1 != q2 {
+                let ord = q1.cmp(&q2);
+                return if reversed { ord.reverse() } else { ord };
+            }
+            let r1 = a - q1 * b;
+            let r2 = c - q2 * d;
+            // compare r1 / b and r2 / d, both in [0, 1)
+            if r1 == T::zero() || r2 == T::zero() {
+                let ord = r1.cmp(&r2);
+                return if reversed { ord.reverse() } else { ord };
+            }
+            // r1 / b < r2 / d iff b / r1 > d / r2
+            a = b;
+            b = r1;
+            c = d;
+            d = r2;
+            reversed = !reversed;
+        }
+    }
+}
+
+impl<T: Integer> fmt::Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == T::one() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Mint998;
+
+    #[test]
+    fn test_arithmetic() {
+        let v: Vec<Rational<i64>> = (-6..=6)
+            .flat_map(|a| (1..=6).map(move |b| Rational::new(a, b)))
+            .collect();
+        for &x in &v {
+            assert!(x.den() > 0);
+            assert_eq!(1, gcd(x.num(), x.den()));
+            assert!(Rational::from(x.floor()) <= x && x < Rational::from(x.floor() + 1));
+            assert!(Rational::from(x.ceil() - 1) < x && x <= Rational::from(x.ceil()));
+            for &y in &v {
+                let (a, b, c, d) = (x.num(), x.den(), y.num(), y.den());
+                assert_eq!(Rational::new(a * d + b * c, b * d), x + y);
+                assert_eq!(Rational::new(a * d - b * c, b * d), x - y);
+                assert_eq!(Rational::new(a * c, b * d), x * y);
+                if c != 0 {
+                    assert_eq!(Rational::new(a * d, b * c), x / y);
+                }
+                assert_eq!((a * d).cmp(&(b * c)), x.cmp(&y));
+                let (mx, my) = (x.to_mint::<crate::math::Mod998>(), y.to_mint());
+                assert_eq!(mx + my, (x + y).to_mint::<crate::math::Mod998>());
+                assert_eq!(mx * my, (x * y).to_mint::<crate::math::Mod998>());
+            }
+        }
+        let mut z = Rational::from(1i128);
+        z += 1;
+        z *= Rational::new(3, 4);
+        z -= Rational::new(1, 2);
+        z /= 2;
+        assert_eq!(Rational::new(1, 2), z);
+        assert_eq!(Mint998::from(2).inv(), z.to_mint());
+    }
+
+    #[test]
+    fn test_large() {
+        let big = i64::max_value();
+        let x = Rational::new(big - 1, big);
+        let y = Rational::new(big - 2, big - 1);
+        assert!(y < x);
+        assert!(-x < -y);
+        assert_eq!(Rational::from(1), x * Rational::new(big, big - 1));
+        assert_eq!(Rational::new(1, big), Rational::from(1) - x);
+        // numerators of the intermediate sum overflow, but the result fits
+        let x = Rational::new((1i64 << 62) - 1, 1 << 62);
+        let y = Rational::new((1i64 << 62) + 1, 1 << 62);
+        assert_eq!(Rational::from(2), x + y);
+        assert_eq!(Rational::new(-1, 1 << 61), x - y);
+        assert_eq!(Rational::from(-2), -x - y);
+        let z = Rational::new(big, 2);
+        assert_eq!(Rational::new(big - 1, 2), z + Rational::new(-1, 2));
+    }
+
+    #[test]
+    fn test_limit_denominator() {
+        for &(a, b) in &[(123_456_789i64, 987_654_321), (3, 4), (-5, 12), (41, 30)] {
+            let x = Rational::new(a, b);
+            for max_den in 1..200 {
+                let best = x.limit_denominator(max_den);
+                assert!(best.den() <= max_den);
+                let dist = (best - x).abs();
+                for q in 1..=max_den {
+                    let p = (x * q).floor();
+                    for &p in &[p, p + 1] {
+                        let d = (Rational::new(p, q) - x).abs();
+                        assert!(d > dist || (d == dist && Rational::new(p, q).den() >= best.den()));
+                    }
+                }
+            }
+        }
+        let y = Rational::new(-7i64, 3);
+        assert_eq!(y, y.limit_denominator(3));
+        assert_eq!(Rational::from(-2), y.limit_denominator(1));
+
+        // near the limits of i64, expected values are from Python's Fraction.limit_denominator
+        let x = Rational::new(123_456_789_012_345_678i64, 999_999_999_999_999_989);
+        assert_eq!(
+            Rational::new(97_393_689, 788_888_888),
+            x.limit_denominator(1_000_000_000)
+        );
+        assert_eq!(Rational::new(10, 81), x.limit_denominator(1000));
+        assert_eq!(Rational::from(0), x.limit_denominator(1));
+        let big = i64::max_value();
+        let y = Rational::new(big - 1, big);
+        assert_eq!(
+            Rational::new(big - 2, big - 1),
+            y.limit_denominator(big - 1)
+        );
+        assert_eq!(
+            Rational::from(1),
+            y.limit_denominator(1_000_000_000_000_000_000)
+        );
+        let z = Rational::new(big, big - 2);
+        assert_eq!(
+            Rational::from(1),
+            z.limit_denominator(1_000_000_000_000_000_000)
+        );
+        assert_eq!(-Rational::from(1), (-z).limit_denominator(5));
+    }
+}
@@ -0,0 +1,387 @@
+//! Trie and Aho–Corasick automaton over byte strings
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+const NONE: usize = usize::max_value();
+
+/// Trie of byte strings. Call [`build`](#method.build) to get an Aho–Corasick automaton.
+///
+/// Node `0` is the root, which corresponds to the empty string.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::Trie;
+/// let mut trie = Trie::new();
+/// assert_eq!(0, trie.insert(b"he"));
+/// assert_eq!(1, trie.insert(b"her"));
+/// assert!(trie.contains(b"he"));
+/// assert!(!trie.contains(b"h"));
+/// assert_eq!(2, trie.count_prefix(b"h"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trie {
+    children: Vec<BTreeMap<u8, usize>>,
+    depth: Vec<usize>,
+    ids: Vec<Vec<usize>>,     // ids[v]: patterns ending at v
+    prefix_count: Vec<usize>, // number of patterns passing through v
+    pattern_node: Vec<usize>,
+}
+
+impl Default for Trie {
+    fn default() -> Trie {
+        Trie::new()
+    }
+}
+
+impl Trie {
+    pub fn new() -> Trie {
+        Trie {
+            children: vec![BTreeMap::new()],
+            depth: vec![0],
+            ids: vec![Vec::new()],
+            prefix_count: vec![0],
+            pattern_node: Vec::new(),
+        }
+    }
+
+    /// Number of nodes including the root.
+    pub fn num_nodes(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Number of inserted patterns.
+    pub fn num_patterns(&self) -> usize {
+        self.pattern_node.len()
+    }
+
+    /// Inserts `pattern` and returns its id, which is the number of patterns inserted before.
+    ///
+    /// O(m log σ)
+    pub fn insert(&mut self, pattern: &[u8]) -> usize {
+        let mut v = 0;
+        self.prefix_count[0] += 1;
+        for &c in pattern {
+            v = match self.children[v].get(&c) {
+                Some(&u) => u,
+                None => {
+                    let u = self.children.len();
+                    self.children[v].insert(c, u);
+                    self.children.push(BTreeMap::new());
+                    self.depth.push(self.depth[v] + 1);
+                    self.ids.push(Vec::new());
+                    self.prefix_count.push(0);
+                    u
+                }
+            };
+            self.prefix_count[v] += 1;
+        }
+        let id = self.pattern_node.len();
+        self.ids[v].push(id);
+        self.pattern_node.push(v);
+        id
+    }
+
+    /// Returns the node corresponding to `s`, if `s` is a prefix of some pattern.
+    pub fn find(&self, s: &[u8]) -> Option<usize> {
+        let mut v = 0;
+        for c in s {
+            v = *self.children[v].get(c)?;
+        }
+        Some(v)
+    }
+
+    /// Returns true if `s` has been inserted.
+    pub fn contains(&self, s: &[u8]) -> bool {
+        self.find(s).map_or(false, |v| !self.ids[v].is_empty())
+    }
+
+    /// Number of inserted patterns (with multiplicity) which start with `s`.
+    pub fn count_prefix(&self, s: &[u8]) -> usize {
+        self.find(s).map_or(0, |v| self.prefix_count[v])
+    }
+
+    /// Builds the Aho–Corasick automaton.
+    ///
+    /// O(n σ) where `n` is the number of nodes and `σ` is the number of distinct bytes in patterns.
+    pub fn build(self) -> AhoCorasick {
+        let alphabet: BTreeSet<u8> = self
+            .children
+            .iter()
+            .flat_map(|ch| ch.keys().cloned())
+            .collect();
+        let mut index = vec![NONE; 256];
+        for (i, &c) in alphabet.iter().enumerate() {
+            index[c as usize] = i;
+        }
+        let sigma = alphabet.len();
+        let n = self.children.len();
+        let mut goto = vec![0; n * sigma];
+        let mut fail = vec![0; n];
+        let mut output = vec![NONE; n];
+        let mut match_count = vec![0; n];
+        let mut order = Vec::with_capacity(n);
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(v) = queue.pop_front() {
+            order.push(v);
+            let f = fail[v];
+            match_count[v] = self.ids[v].len() + if v == 0 { 0 } else { match_count[f] };
+            if v != 0 {
+                output[v] = if self.ids[f].is_empty() { output[f] } else { f };
+            }
+            for (&c, &u) in &self.children[v] {
+                fail[u] = if v == 0 {
+                    0
+                } else {
+                    goto[f * sigma + index[c as usize]]
+                };
+                queue.push_back(u);
+            }
+            for i in 0..sigma {
+                goto[v * sigma + i] = if v == 0 { 0 } else { goto[f * sigma + i] };
+            }
+            for (&c, &u) in &self.children[v] {
+                goto[v * sigma + index[c as usize]] = u;
+            }
+        }
+        AhoCorasick {
+            index,
+            sigma,
+            goto,
+            fail,
+            output,
+            match_count,
+            order,
+            depth: self.depth,
+            ids: self.ids,
+            pattern_node: self.pattern_node,
+        }
+    }
+}
+
+/// Aho–Corasick automaton for multiple pattern matching, built by [`Trie::build`](struct.Trie.html#method.build).
+///
+/// States are the nodes of the trie, and the state after reading a text is the longest
+/// suffix of the text which is a prefix of some pattern. This is useful for DP over strings
+/// avoiding patterns with [`next`](#method.next) and [`match_count`](#method.match_count).
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::Trie;
+/// let mut trie = Trie::new();
+/// for p in &["he", "she", "his", "hers"] {
+///     trie.insert(p.as_bytes());
+/// }
+/// let ac = trie.build();
+/// assert_eq!(vec![1, 1, 0, 1], ac.count_occurrences(b"ushers"));
+/// // (start, pattern id) sorted by the end position
+/// assert_eq!(vec![(1, 1), (2, 0), (2, 3)], ac.find_all(b"ushers"));
+///
+/// let mut state = ac.root();
+/// for &c in b"ush" {
+///     state = ac.next(state, c);
+/// }
+/// assert_eq!(0, ac.match_count(state));
+/// assert_eq!(2, ac.match_count(ac.next(state, b'e')));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    index: Vec<usize>, // byte -> index in the alphabet
+    sigma: usize,
+    goto: Vec<usize>,
+    fail: Vec<usize>,
+    output: Vec<usize>,
+    match_count: Vec<usize>,
+    order: Vec<usize>, // BFS order
+    depth: Vec<usize>,
+    ids: Vec<Vec<usize>>,
+    pattern_node: Vec<usize>,
+}
+
+impl AhoCorasick {
+    pub fn root(&self) -> usize {
+        0
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.fail.len()
+    }
+
+    /// Returns the state after reading `c` at `state` in O(1).
+    pub fn next(&self, state: usize, c: u8) -> usize {
+        match self.index[c as usize] {
+            NONE => 0,
+            i => self.goto[state * self.sigma + i],
+        }
+    }
+
+    /// Failure link: the state of the longest proper suffix.
+    pub fn fail(&self, state: usize) -> usize {
+        self.fail[state]
+    }
+
+    /// Output link: the state of the longest proper suffix where some pattern ends, if exists.
+    pub fn output_link(&self, state: usize) -> Option<usize> {
+        match self.output[state] {
+            NONE => None,
+            v => Some(v),
+        }
+    }
+
+    /// Length of the string corresponding to `state`.
+    pub fn depth(&self, state: usize) -> usize {
+        self.depth[state]
+    }
+
+    /// Ids of patterns which are equal to the string of `state`.
+    pub fn patterns_at(&self, state: usize) -> &[usize] {
+        &self.ids[state]
+    }
+
+    /// Number of patterns which are suffixes of the string of `state`.
+    pub fn match_count(&self, state: usize) -> usize {
+        self.match_count[state]
+    }
+
+    /// Returns the state corresponding to the pattern `id`.
+    pub fn pattern_state(&self, id: usize) -> usize {
+        self.pattern_node[id]
+    }
+
+    /// Returns the number of occurrences of each pattern in `text`.
+    ///
+    /// O(|text| + n)
+    pub fn count_occurrences(&self, text: &[u8]) -> Vec<usize> {
+        let mut cnt = vec![0; self.num_states()];
+        let mut state = 0;
+        for &c in text {
+            state = self.next(state, c);
+            cnt[state] += 1;
+        }
+        // propagate counts to suffixes
+        for &v in self.order.iter().skip(1).rev() {
+            cnt[self.fail[v]] += cnt[v];
+        }
+        cnt[0] = text.len() + 1; // the empty pattern occurs at every position
+        self.pattern_node.iter().map(|&v| cnt[v]).collect()
+    }
+
+    /// Returns all occurrences as `(start, pattern id)` sorted by the end position.
+    ///
+    /// Empty patterns are ignored.
+    ///
+    /// O(|text| + number of occurrences)
+    pub fn find_all(&self, text: &[u8]) -> Vec<(usize, usize)> {
+        let mut res = Vec::new();
+        let mut state = 0;
+        for (i, &c) in text.iter().enumerate() {
+            state = self.next(state, c);
+            let mut v = if self.ids[state].is_empty() {
+                self.output[state]
+            } else {
+                state
+            };
+            while v != NONE && v != 0 {
+                for &id in &self.ids[v] {
+                    res.push((i + 1 - self.depth[v], id));
+                }
+                v = self.output[v];
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    #[test]
+    fn test_trie() {
+        let mut trie = Trie::new();
+        let words: Vec<&[u8]> = vec![b"abc", b"ab", b"abd", b"b", b"abc"];
+        for (i, w) in words.iter().enumerate() {
+            assert_eq!(i, trie.insert(w));
+        }
+        assert_eq!(5, trie.num_patterns());
+        assert_eq!(6, trie.num_nodes());
+        assert!(trie.contains(b"ab"));
+        assert!(!trie.contains(b"a"));
+        assert!(!trie.contains(b"abcd"));
+        assert_eq!(5, trie.count_prefix(b""));
+        assert_eq!(4, trie.count_prefix(b"a"));
+        assert_eq!(2, trie.count_prefix(b"abc"));
+        assert_eq!(0, trie.count_prefix(b"c"));
+        assert_eq!(
+            trie.find(b"abc"),
+            trie.clone().build().pattern_state(4).into()
+        );
+    }
+
+    #[test]
+    fn test_aho_corasick() {
+        let mut rng = XorShift::new();
+        for _ in 0..100 {
+            let mut trie = Trie::new();
+            let patterns: Vec<Vec<u8>> = (0..rng.next_u32() % 8)
+                .map(|_| {
+                    let len = (rng.next_u32() % 4) as usize;
+                    (0..len)
+                        .map(|_| b'a' + (rng.next_u32() % 3) as u8)
+                        .collect()
+                })
+                .collect();
+            for p in &patterns {
+                trie.insert(p);
+            }
+            let ac = trie.build();
+            let text: Vec<u8> = (0..50).map(|_| b'a' + (rng.next_u32() % 4) as u8).collect();
+            let mut expected_all = Vec::new();
+            for end in 0..=text.len() {
+                for (id, p) in patterns.iter().enumerate() {
+                    if !p.is_empty() && p.len() <= end && text[end - p.len()..end] == p[..] {
+                        expected_all.push((end, p.len(), id));
+                    }
+                }
+            }
+            // order of patterns ending at the same position is by decreasing length
+            expected_all.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)).then(a.2.cmp(&b.2)));
+            let expected_all: Vec<(usize, usize)> = expected_all
+                .into_iter()
+                .map(|(end, len, id)| (end - len, id))
+                .collect();
+            assert_eq!(expected_all, ac.find_all(&text));
+            let expected_count: Vec<usize> = patterns
+                .iter()
+                .map(|p| {
+                    (0..=text.len())
+                        .filter(|&i| i + p.len() <= text.len() && text[i..i + p.len()] == p[..])
+                        .count()
+                })
+                .collect();
+            assert_eq!(expected_count, ac.count_occurrences(&text));
+            // the state is the longest suffix which is a prefix of some pattern
+            let mut state = ac.root();
+            for i in 0..text.len() {
+                state = ac.next(state, text[i]);
+                let d = ac.depth(state);
+                assert!(
+                    d == 0
+                        || patterns
+                            .iter()
+                            .any(|p| p.len() >= d && p[..d] == text[i + 1 - d..=i])
+                );
+                for p in &patterns {
+                    for k in d + 1..=std::cmp::min(p.len(), i + 1) {
+                        assert_ne!(p[..k], text[i + 1 - k..=i]);
+                    }
+                }
+                let matched = patterns
+                    .iter()
+                    .filter(|p| p.len() <= i + 1 && p[..] == text[i + 1 - p.len()..=i])
+                    .count();
+                assert_eq!(matched, ac.match_count(state));
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! string algorithms
+//!
+//! All functions are generic over slices, so they work for `&[u8]`, `&[char]` or any other sequences.
+//!
+//! * [`Trie`](struct.Trie.html), [`AhoCorasick`](struct.AhoCorasick.html) - trie and multiple pattern matching
+//! * [`z_algorithm`](fn.z_algorithm.html) - longest common prefix of the string and each suffix
+//! * [`prefix_function`](fn.prefix_function.html), [`kmp_search`](fn.kmp_search.html) - pattern matching by Knuth–Morris–Pratt algorithm
+//! * [`manacher`](fn.manacher.html) - longest palindromes for each center
+//! * [`RollingHash`](struct.RollingHash.html) - polynomial hash modulo 2^61 - 1 with a random base
+//! * [`suffix_array`](fn.suffix_array.html), [`lcp_array`](fn.lcp_array.html) - suffix array by SA-IS and LCP array by Kasai's algorithm
+//! * [`LcpQuery`](struct.LcpQuery.html) - longest common prefix of two suffixes
+//! * [`SuffixAutomaton`](struct.SuffixAutomaton.html) - distinct substrings, occurrences and longest common substring
+//!
+mod aho_corasick;
+mod prefix;
+mod rolling_hash;
+mod suffix_array;
+mod suffix_automaton;
+
+pub use self::aho_corasick::{AhoCorasick, Trie};
+pub use self::prefix::{kmp_search, manacher, prefix_function, z_algorithm};
+pub use self::rolling_hash::RollingHash;
+pub use self::suffix_array::{lcp_array, suffix_array, LcpQuery};
+pub use self::suffix_automaton::{longest_common_substring, SuffixAutomaton};
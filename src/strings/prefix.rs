@@ -0,0 +1,196 @@
+//! Z-algorithm, prefix function (KMP) and Manacher's algorithm
+
+/// Returns `z` where `z[i]` is the length of the longest common prefix of `s` and `s[i..]`.
+///
+/// `z[0]` is `s.len()`.
+///
+/// O(n)
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::z_algorithm;
+/// assert_eq!(vec![7, 0, 1, 0, 3, 0, 1], z_algorithm(b"abacaba"));
+/// ```
+pub fn z_algorithm<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    // s[l..r] is the rightmost match found so far
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = std::cmp::min(r - i, z[i - l]);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+/// Returns `pi` where `pi[i]` is the length of the longest proper border of `s[..=i]`.
+///
+/// O(n)
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::prefix_function;
+/// assert_eq!(vec![0, 0, 1, 0, 1, 2, 3], prefix_function(b"abacaba"));
+/// ```
+pub fn prefix_function<T: Eq>(s: &[T]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[k] != s[i] {
+            k = pi[k - 1];
+        }
+        if s[k] == s[i] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Returns all starting positions of `pattern` in `text` in increasing order.
+///
+/// O(n + m) by Knuth–Morris–Pratt algorithm.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::kmp_search;
+/// assert_eq!(vec![0, 2, 7], kmp_search(b"abababcaba", b"aba"));
+/// ```
+pub fn kmp_search<T: Eq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let pi = prefix_function(pattern);
+    let mut res = Vec::new();
+    let mut k = 0;
+    for (i, c) in text.iter().enumerate() {
+        while k > 0 && (k == m || pattern[k] != *c) {
+            k = pi[k - 1];
+        }
+        if pattern[k] == *c {
+            k += 1;
+        }
+        if k == m {
+            res.push(i + 1 - m);
+        }
+    }
+    res
+}
+
+/// Returns `r` of length `2n - 1` where `r[i]` is the length of the longest palindrome
+/// centered at `s[i / 2]` for even `i`, or between `s[i / 2]` and `s[i / 2 + 1]` for odd `i`.
+///
+/// O(n) by Manacher's algorithm.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::manacher;
+/// // centers:          a  .  b  .  b  .  a  .  c
+/// assert_eq!(vec![1, 0, 1, 4, 1, 0, 1, 0, 1], manacher(b"abbac"));
+/// ```
+pub fn manacher<T: Eq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    // run the odd-length version on s interleaved with separators, where index 2i is s[i]
+    let m = 2 * n - 1;
+    let eq = |i: usize, j: usize| i % 2 == 1 || s[i / 2] == s[j / 2];
+    let mut rad = vec![0; m]; // radius including the center
+    let (mut c, mut r) = (0, 0);
+    for i in 0..m {
+        let mut k = if i < r {
+            std::cmp::min(rad[2 * c - i], r - i)
+        } else {
+            1
+        };
+        while k <= i && i + k < m && eq(i - k, i + k) {
+            k += 1;
+        }
+        rad[i] = k;
+        if i + k > r {
+            c = i;
+            r = i + k;
+        }
+    }
+    // t[i - k + 1..i + k] is a palindrome, and its characters at even indices are from s
+    rad.iter()
+        .enumerate()
+        .map(|(i, &k)| if (i + k) % 2 == 1 { k } else { k - 1 })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    #[test]
+    fn test_z_and_prefix_function() {
+        let mut rng = XorShift::new();
+        for n in 0..50 {
+            for &alphabet in &[1, 2, 3] {
+                let s: Vec<u8> = (0..n).map(|_| (rng.next_u32() % alphabet) as u8).collect();
+                let z = z_algorithm(&s);
+                let pi = prefix_function(&s);
+                for i in 0..n {
+                    let expected = (0..=n - i).filter(|&k| s[..k] == s[i..i + k]).max();
+                    assert_eq!(expected, Some(z[i]));
+                    let expected = (0..=i).filter(|&k| s[..k] == s[i + 1 - k..=i]).max();
+                    assert_eq!(expected, Some(pi[i]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kmp_search() {
+        let mut rng = XorShift::new();
+        for n in 0..50 {
+            for m in 0..5 {
+                let text: Vec<u8> = (0..n).map(|_| (rng.next_u32() % 2) as u8).collect();
+                let pattern: Vec<u8> = (0..m).map(|_| (rng.next_u32() % 2) as u8).collect();
+                let expected: Vec<usize> = (0..=n)
+                    .filter(|&i| i + m <= n && text[i..i + m] == pattern[..])
+                    .collect();
+                assert_eq!(expected, kmp_search(&text, &pattern));
+            }
+        }
+    }
+
+    #[test]
+    fn test_manacher() {
+        let mut rng = XorShift::new();
+        for n in 0..50usize {
+            let s: Vec<u8> = (0..n).map(|_| (rng.next_u32() % 2) as u8).collect();
+            let r = manacher(&s);
+            assert_eq!(n.saturating_mul(2).saturating_sub(1), r.len());
+            for (i, &len) in r.iter().enumerate() {
+                let is_palindrome = |l: usize, r: usize| (l..r).all(|k| s[k] == s[l + r - 1 - k]);
+                let expected = (0..=n)
+                    .filter(|&len| {
+                        len % 2 == (i + 1) % 2 && i + 1 >= len && (i + 1 - len) / 2 + len <= n && {
+                            let l = (i + 1 - len) / 2;
+                            is_palindrome(l, l + len)
+                        }
+                    })
+                    .max()
+                    .unwrap();
+                assert_eq!(expected, len);
+            }
+        }
+    }
+}
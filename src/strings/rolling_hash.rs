@@ -0,0 +1,199 @@
+//! Rolling hash modulo 2^61 - 1
+use crate::math::{Mint61, MOD_61};
+use crate::util::XorShift;
+use std::cmp;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+thread_local! {
+    static BASE: Mint61 = random_base();
+}
+
+/// Returns a random base in `[2, 2^61 - 2]` seeded from the current time and a stack address,
+/// so that hacks against a fixed base do not work.
+fn random_base() -> Mint61 {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let local = 0u8;
+    let addr = &local as *const u8 as u64;
+    let mut rng = XorShift::with_seed(time ^ addr.rotate_left(32));
+    let r = (rng.next_u32() as u64) << 32 | rng.next_u32() as u64;
+    Mint61::from(r % (MOD_61 - 3) + 2)
+}
+
+/// Polynomial hash of a sequence modulo `2^61 - 1`.
+///
+/// `hash(s) = s[0] * base^(n - 1) + s[1] * base^(n - 2) + ... + s[n - 1]`
+///
+/// Hashes should be compared between sequences of the same length,
+/// because leading zeros do not change the hash.
+///
+/// The base is chosen randomly once per thread, so hashes of different `RollingHash` values
+/// created by `new` on the same thread are comparable.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::RollingHash;
+/// let a = RollingHash::new(b"abracadabra");
+/// let b = RollingHash::new(b"cadabra");
+/// assert_eq!(a.hash(0, 4), a.hash(7, 11));
+/// assert_eq!(a.hash(4, 11), b.hash(0, 7));
+/// assert_ne!(a.hash(0, 4), a.hash(1, 5));
+/// assert_eq!(a.hash(0, 11), a.concat(a.hash(0, 4), a.hash(4, 11), 7));
+/// assert_eq!(4, a.lcp(0, &a, 7));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    base: Mint61,
+    values: Vec<u64>,
+    hash: Vec<Mint61>, // hash[i] is the hash of values[..i]
+    pow: Vec<Mint61>,  // pow[i] is base^i
+}
+
+impl RollingHash {
+    /// Creates a hash with the random base of the current thread.
+    ///
+    /// O(n)
+    pub fn new<T: Copy + Into<u64>>(s: &[T]) -> RollingHash {
+        RollingHash::with_base(s, BASE.with(|&b| b))
+    }
+
+    /// Creates a hash with the given base.
+    ///
+    /// O(n)
+    pub fn with_base<T: Copy + Into<u64>>(s: &[T], base: Mint61) -> RollingHash {
+        let values: Vec<u64> = s.iter().map(|&c| c.into()).collect();
+        let mut hash = Vec::with_capacity(values.len() + 1);
+        let mut pow = Vec::with_capacity(values.len() + 1);
+        hash.push(Mint61::from(0));
+        pow.push(Mint61::from(1));
+        for (i, &c) in values.iter().enumerate() {
+            hash.push(hash[i] * base + c);
+            pow.push(pow[i] * base);
+        }
+        RollingHash {
+            base,
+            values,
+            hash,
+            pow,
+        }
+    }
+
+    pub fn base(&self) -> Mint61 {
+        self.base
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the hash of `s[l..r]` in O(1).
+    pub fn hash(&self, l: usize, r: usize) -> Mint61 {
+        assert!(l <= r && r <= self.len());
+        self.hash[r] - self.hash[l] * self.pow[r - l]
+    }
+
+    /// Returns the hash of the concatenation of two sequences, where `len2` is the length of the second one.
+    ///
+    /// O(1) if `len2 <= self.len()`, O(log len2) otherwise.
+    pub fn concat(&self, hash1: Mint61, hash2: Mint61, len2: usize) -> Mint61 {
+        let pow = match self.pow.get(len2) {
+            Some(&p) => p,
+            None => self.base.pow(len2 as u64),
+        };
+        hash1 * pow + hash2
+    }
+
+    /// Returns the length of the longest common prefix of `self[i..]` and `other[j..]`.
+    ///
+    /// O(log n) by binary search. Both should have the same base.
+    pub fn lcp(&self, i: usize, other: &RollingHash, j: usize) -> usize {
+        assert!(self.base == other.base, "bases are different");
+        let (mut ok, mut ng) = (0, cmp::min(self.len() - i, other.len() - j) + 1);
+        while ng - ok > 1 {
+            let mid = (ok + ng) / 2;
+            if self.hash(i, i + mid) == other.hash(j, j + mid) {
+                ok = mid;
+            } else {
+                ng = mid;
+            }
+        }
+        ok
+    }
+
+    /// Compares `self[l1..r1]` and `other[l2..r2]` lexicographically.
+    ///
+    /// O(log n) by [`lcp`](#method.lcp).
+    pub fn compare(
+        &self,
+        l1: usize,
+        r1: usize,
+        other: &RollingHash,
+        l2: usize,
+        r2: usize,
+    ) -> cmp::Ordering {
+        assert!(l1 <= r1 && r1 <= self.len() && l2 <= r2 && r2 <= other.len());
+        let k = cmp::min(self.lcp(l1, other, l2), cmp::min(r1 - l1, r2 - l2));
+        if l1 + k == r1 || l2 + k == r2 {
+            (r1 - l1).cmp(&(r2 - l2))
+        } else {
+            self.values[l1 + k].cmp(&other.values[l2 + k])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_hash() {
+        let mut rng = XorShift::new();
+        let s: Vec<u8> = (0..60).map(|_| (rng.next_u32() % 2) as u8).collect();
+        let h = RollingHash::new(&s);
+        assert_eq!(s.len(), h.len());
+        for l1 in 0..=s.len() {
+            for r1 in l1..=s.len() {
+                for &(l2, r2) in &[(0, r1 - l1), (3, 10), (l1 / 2, r1)] {
+                    if r2 > s.len() || l2 > r2 {
+                        continue;
+                    }
+                    let (a, b) = (&s[l1..r1], &s[l2..r2]);
+                    if a.len() == b.len() {
+                        assert_eq!(a == b, h.hash(l1, r1) == h.hash(l2, r2));
+                    }
+                    assert_eq!(a.cmp(b), h.compare(l1, r1, &h, l2, r2));
+                }
+            }
+        }
+        for i in 0..s.len() {
+            let expected = s[i..].iter().zip(&s).take_while(|(x, y)| x == y).count();
+            assert_eq!(expected, h.lcp(i, &h, 0));
+            assert_eq!(
+                h.hash(0, s.len()),
+                h.concat(h.hash(0, i), h.hash(i, s.len()), s.len() - i)
+            );
+        }
+    }
+
+    #[test]
+    fn test_base() {
+        let h1 = RollingHash::new(b"abc");
+        let h2 = RollingHash::new(b"xabc");
+        assert_eq!(h1.base(), h2.base());
+        assert_eq!(h1.hash(0, 3), h2.hash(1, 4));
+        assert_eq!(h1.hash(0, 3), h1.concat(h1.hash(0, 1), h2.hash(2, 4), 2));
+        let fixed = RollingHash::with_base(&[1u32, 2, 3], Mint61::from(10));
+        assert_eq!(Mint61::from(123), fixed.hash(0, 3));
+        assert_eq!(
+            Mint61::from(123_000_023),
+            fixed.concat(fixed.hash(0, 3), fixed.hash(1, 3), 6)
+        );
+        assert!(RollingHash::new::<u8>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,285 @@
+//! Suffix array and LCP array
+// ref: https://github.com/atcoder/ac-library/blob/master/atcoder/string.hpp
+use crate::data_structure::RMQ;
+
+const NONE: usize = usize::max_value();
+const NAIVE_THRESHOLD: usize = 10;
+
+/// Returns the suffix array of `s`, i.e. the starting positions of suffixes in lexicographic order.
+///
+/// O(n log n) for compressing the alphabet and O(n) by SA-IS algorithm.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::suffix_array;
+/// // a, abra, abracadabra, acadabra, adabra, bra, bracadabra, cadabra, dabra, ra, racadabra
+/// assert_eq!(vec![10, 7, 0, 3, 5, 8, 1, 4, 6, 9, 2], suffix_array(b"abracadabra"));
+/// ```
+pub fn suffix_array<T: Ord>(s: &[T]) -> Vec<usize> {
+    let mut sorted: Vec<&T> = s.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+    let ranks: Vec<usize> = s
+        .iter()
+        .map(|c| sorted.binary_search(&c).unwrap())
+        .collect();
+    sa_is(&ranks, sorted.len().saturating_sub(1))
+}
+
+fn sa_naive(s: &[usize]) -> Vec<usize> {
+    let mut sa: Vec<usize> = (0..s.len()).collect();
+    sa.sort_by(|&i, &j| s[i..].cmp(&s[j..]));
+    sa
+}
+
+/// SA-IS for `s` whose values are in `0..=upper`.
+fn sa_is(s: &[usize], upper: usize) -> Vec<usize> {
+    let n = s.len();
+    if n < NAIVE_THRESHOLD {
+        return sa_naive(s);
+    }
+    // ls[i]: whether s[i..] is S-type (smaller than s[i + 1..])
+    let mut ls = vec![false; n];
+    for i in (0..n - 1).rev() {
+        ls[i] = if s[i] == s[i + 1] {
+            ls[i + 1]
+        } else {
+            s[i] < s[i + 1]
+        };
+    }
+    // bucket boundaries for L-type and S-type suffixes
+    let mut sum_l = vec![0; upper + 1];
+    let mut sum_s = vec![0; upper + 1];
+    for i in 0..n {
+        if !ls[i] {
+            sum_s[s[i]] += 1;
+        } else if s[i] < upper {
+            sum_l[s[i] + 1] += 1;
+        }
+    }
+    for i in 0..=upper {
+        sum_s[i] += sum_l[i];
+        if i < upper {
+            sum_l[i + 1] += sum_s[i];
+        }
+    }
+
+    let induce = |lms: &[usize]| -> Vec<usize> {
+        let mut sa = vec![NONE; n];
+        let mut buf = sum_s.clone();
+        for &d in lms {
+            if d == n {
+                continue;
+            }
+            sa[buf[s[d]]] = d;
+            buf[s[d]] += 1;
+        }
+        buf.copy_from_slice(&sum_l);
+        sa[buf[s[n - 1]]] = n - 1;
+        buf[s[n - 1]] += 1;
+        for i in 0..n {
+            let v = sa[i];
+            if v != NONE && v >= 1 && !ls[v - 1] {
+                sa[buf[s[v - 1]]] = v - 1;
+                buf[s[v - 1]] += 1;
+            }
+        }
+        buf.copy_from_slice(&sum_l);
+        for i in (0..n).rev() {
+            let v = sa[i];
+            if v != NONE && v >= 1 && ls[v - 1] {
+                buf[s[v - 1] + 1] -= 1;
+                sa[buf[s[v - 1] + 1]] = v - 1;
+            }
+        }
+        sa
+    };
+
+    // sort LMS substrings by induced sorting
+    let mut lms_map = vec![NONE; n + 1];
+    let mut lms = Vec::new();
+    for i in 1..n {
+        if !ls[i - 1] && ls[i] {
+            lms_map[i] = lms.len();
+            lms.push(i);
+        }
+    }
+    let m = lms.len();
+    let mut sa = induce(&lms);
+    if m == 0 {
+        return sa;
+    }
+
+    // name LMS substrings and sort LMS suffixes recursively
+    let mut sorted_lms: Vec<usize> = sa.iter().cloned().filter(|&v| lms_map[v] != NONE).collect();
+    let mut rec_s = vec![0; m];
+    let mut rec_upper = 0;
+    rec_s[lms_map[sorted_lms[0]]] = 0;
+    for i in 1..m {
+        let (mut l, mut r) = (sorted_lms[i - 1], sorted_lms[i]);
+        let end_l = if lms_map[l] + 1 < m {
+            lms[lms_map[l] + 1]
+        } else {
+            n
+        };
+        let end_r = if lms_map[r] + 1 < m {
+            lms[lms_map[r] + 1]
+        } else {
+            n
+        };
+        let mut same = true;
+        if end_l - l != end_r - r {
+            same = false;
+        } else {
+            while l < end_l && s[l] == s[r] {
+                l += 1;
+                r += 1;
+            }
+            if l == n || s[l] != s[r] {
+                same = false;
+            }
+        }
+        if !same {
+            rec_upper += 1;
+        }
+        rec_s[lms_map[sorted_lms[i]]] = rec_upper;
+    }
+    let rec_sa = sa_is(&rec_s, rec_upper);
+    for i in 0..m {
+        sorted_lms[i] = lms[rec_sa[i]];
+    }
+    sa = induce(&sorted_lms);
+    sa
+}
+
+/// Returns `lcp` of length `n - 1` where `lcp[i]` is the length of the longest common prefix
+/// of suffixes `s[sa[i]..]` and `s[sa[i + 1]..]`.
+///
+/// O(n) by Kasai's algorithm.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::{lcp_array, suffix_array};
+/// let s = b"abracadabra";
+/// let sa = suffix_array(s);
+/// assert_eq!(vec![1, 4, 1, 1, 0, 3, 0, 0, 0, 2], lcp_array(s, &sa));
+/// ```
+pub fn lcp_array<T: Eq>(s: &[T], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0; n - 1];
+    let mut h: usize = 0;
+    for i in 0..n {
+        h = h.saturating_sub(1);
+        if rank[i] == 0 {
+            continue;
+        }
+        let j = sa[rank[i] - 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i] - 1] = h;
+    }
+    lcp
+}
+
+/// Longest common prefix of two suffixes in O(log n) by a segment tree over the LCP array.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::LcpQuery;
+/// let q = LcpQuery::new(b"abracadabra");
+/// assert_eq!(4, q.lcp(0, 7));
+/// assert_eq!(1, q.lcp(0, 3));
+/// assert_eq!(0, q.lcp(1, 2));
+/// assert_eq!(11, q.lcp(0, 0));
+/// ```
+pub struct LcpQuery {
+    sa: Vec<usize>,
+    rank: Vec<usize>,
+    rmq: RMQ<usize>,
+}
+
+impl LcpQuery {
+    /// O(n log n)
+    pub fn new<T: Ord>(s: &[T]) -> LcpQuery {
+        let sa = suffix_array(s);
+        let mut rank = vec![0; s.len()];
+        for (i, &p) in sa.iter().enumerate() {
+            rank[p] = i;
+        }
+        let rmq = RMQ::from_vec(lcp_array(s, &sa));
+        LcpQuery { sa, rank, rmq }
+    }
+
+    pub fn suffix_array(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// Position of the suffix `s[i..]` in the suffix array.
+    pub fn rank(&self, i: usize) -> usize {
+        self.rank[i]
+    }
+
+    /// Length of the longest common prefix of `s[i..]` and `s[j..]`.
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.sa.len() - i;
+        }
+        let (ri, rj) = (self.rank[i], self.rank[j]);
+        let (l, r) = if ri < rj { (ri, rj) } else { (rj, ri) };
+        self.rmq.query(l, r).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+
+    #[test]
+    fn test_suffix_array() {
+        let mut rng = XorShift::new();
+        for n in 0..200 {
+            for &alphabet in &[1, 2, 3, 26, 1000] {
+                let s: Vec<u32> = (0..n).map(|_| rng.next_u32() % alphabet).collect();
+                let sa = suffix_array(&s);
+                let mut expected: Vec<usize> = (0..n).collect();
+                expected.sort_by(|&i, &j| s[i..].cmp(&s[j..]));
+                assert_eq!(expected, sa);
+                let lcp = lcp_array(&s, &sa);
+                for i in 0..lcp.len() {
+                    let (a, b) = (&s[sa[i]..], &s[sa[i + 1]..]);
+                    let expected = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+                    assert_eq!(expected, lcp[i]);
+                }
+            }
+        }
+        let words = vec!["b", "a", "c", "a", "b"];
+        assert_eq!(vec![3, 1, 4, 0, 2], suffix_array(&words));
+    }
+
+    #[test]
+    fn test_lcp_query() {
+        let mut rng = XorShift::new();
+        let s: Vec<u8> = (0..100).map(|_| (rng.next_u32() % 2) as u8).collect();
+        let q = LcpQuery::new(&s);
+        for i in 0..s.len() {
+            assert_eq!(i, q.suffix_array()[q.rank(i)]);
+            for j in 0..s.len() {
+                let expected = s[i..]
+                    .iter()
+                    .zip(&s[j..])
+                    .take_while(|(x, y)| x == y)
+                    .count();
+                assert_eq!(expected, q.lcp(i, j));
+            }
+        }
+    }
+}
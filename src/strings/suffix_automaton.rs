@@ -0,0 +1,291 @@
+//! Suffix automaton
+use std::collections::BTreeMap;
+
+const NONE: usize = usize::max_value();
+
+#[derive(Debug, Clone)]
+struct State<T> {
+    len: usize,
+    link: usize,
+    next: BTreeMap<T, usize>,
+    first_end: usize, // end position (exclusive) of the first occurrence
+    occurrences: usize,
+    paths: u64, // number of distinct strings (including empty) readable from this state
+}
+
+/// Suffix automaton, the minimum DFA which accepts all suffixes of a sequence.
+///
+/// Each state corresponds to a set of substrings with the same set of end positions.
+/// State `0` is the initial state, which corresponds to the empty string.
+///
+/// O(n log σ) construction where σ is the size of the alphabet.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::SuffixAutomaton;
+/// let sa = SuffixAutomaton::new(b"abab");
+/// // a, b, ab, ba, aba, bab, abab
+/// assert_eq!(7, sa.count_distinct());
+/// assert_eq!(2, sa.count_occurrences(b"ab"));
+/// assert_eq!(0, sa.count_occurrences(b"aa"));
+/// assert_eq!(Some(b"ab".to_vec()), sa.kth_substring(1));
+/// assert_eq!(Some(b"bab".to_vec()), sa.kth_substring(6));
+/// assert_eq!(None, sa.kth_substring(7));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixAutomaton<T> {
+    states: Vec<State<T>>,
+}
+
+impl<T: Ord + Clone> SuffixAutomaton<T> {
+    pub fn new(s: &[T]) -> SuffixAutomaton<T> {
+        let mut states = Vec::with_capacity(2 * s.len() + 1);
+        states.push(State {
+            len: 0,
+            link: NONE,
+            next: BTreeMap::new(),
+            first_end: 0,
+            occurrences: 0,
+            paths: 0,
+        });
+        let mut last = 0;
+        for c in s {
+            let cur = states.len();
+            states.push(State {
+                len: states[last].len + 1,
+                link: 0,
+                next: BTreeMap::new(),
+                first_end: states[last].len + 1,
+                occurrences: 1,
+                paths: 0,
+            });
+            let mut p = last;
+            while p != NONE && !states[p].next.contains_key(c) {
+                states[p].next.insert(c.clone(), cur);
+                p = states[p].link;
+            }
+            if p != NONE {
+                let q = states[p].next[c];
+                if states[p].len + 1 == states[q].len {
+                    states[cur].link = q;
+                } else {
+                    // split q by cloning it with the shorter length
+                    let clone = states.len();
+                    let mut state = states[q].clone();
+                    state.len = states[p].len + 1;
+                    state.occurrences = 0;
+                    states.push(state);
+                    while p != NONE && states[p].next.get(c) == Some(&q) {
+                        states[p].next.insert(c.clone(), clone);
+                        p = states[p].link;
+                    }
+                    states[q].link = clone;
+                    states[cur].link = clone;
+                }
+            }
+            last = cur;
+        }
+        // process states in decreasing order of len by counting sort
+        let mut order: Vec<usize> = (0..states.len()).collect();
+        let mut count = vec![0; s.len() + 2];
+        for st in &states {
+            count[st.len + 1] += 1;
+        }
+        for i in 1..count.len() {
+            count[i] += count[i - 1];
+        }
+        for (v, st) in states.iter().enumerate() {
+            order[count[st.len]] = v;
+            count[st.len] += 1;
+        }
+        for &v in order.iter().rev() {
+            let link = states[v].link;
+            if link != NONE {
+                states[link].occurrences += states[v].occurrences;
+            }
+            let paths = 1 + states[v]
+                .next
+                .values()
+                .map(|&u| states[u].paths)
+                .sum::<u64>();
+            states[v].paths = paths;
+        }
+        SuffixAutomaton { states }
+    }
+
+    pub fn num_states(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns the state after reading `c` at `state`.
+    pub fn next(&self, state: usize, c: &T) -> Option<usize> {
+        self.states[state].next.get(c).cloned()
+    }
+
+    /// Suffix link: the state of the longest suffix in a different state.
+    pub fn link(&self, state: usize) -> Option<usize> {
+        match self.states[state].link {
+            NONE => None,
+            v => Some(v),
+        }
+    }
+
+    /// Length of the longest substring in `state`.
+    pub fn state_len(&self, state: usize) -> usize {
+        self.states[state].len
+    }
+
+    /// Returns true if the automaton is built from an empty sequence.
+    pub fn is_empty(&self) -> bool {
+        self.states.len() == 1
+    }
+
+    /// Number of occurrences of substrings in `state`.
+    pub fn occurrences(&self, state: usize) -> usize {
+        self.states[state].occurrences
+    }
+
+    /// Returns the state reached by reading `pattern`, if `pattern` is a substring.
+    pub fn find(&self, pattern: &[T]) -> Option<usize> {
+        let mut v = 0;
+        for c in pattern {
+            v = self.next(v, c)?;
+        }
+        Some(v)
+    }
+
+    /// Number of occurrences of `pattern` (possibly overlapping).
+    pub fn count_occurrences(&self, pattern: &[T]) -> usize {
+        if pattern.is_empty() {
+            return self.states.iter().map(|st| st.len).max().unwrap() + 1;
+        }
+        self.find(pattern).map_or(0, |v| self.states[v].occurrences)
+    }
+
+    /// Number of distinct non-empty substrings.
+    pub fn count_distinct(&self) -> u64 {
+        self.states[0].paths - 1
+    }
+
+    /// Returns the `k`-th (0-indexed) distinct non-empty substring in lexicographic order.
+    ///
+    /// O(|result| σ)
+    pub fn kth_substring(&self, mut k: u64) -> Option<Vec<T>> {
+        if k >= self.count_distinct() {
+            return None;
+        }
+        let mut res = Vec::new();
+        let mut v = 0;
+        loop {
+            for (c, &u) in &self.states[v].next {
+                if k < self.states[u].paths {
+                    res.push(c.clone());
+                    v = u;
+                    break;
+                }
+                k -= self.states[u].paths;
+            }
+            if k == 0 {
+                return Some(res);
+            }
+            k -= 1; // skip the string ending at v
+        }
+    }
+}
+
+/// Returns `(i, j, len)` such that `a[i..i + len] == b[j..j + len]` with the maximum `len`.
+///
+/// O((n + m) log σ) by the suffix automaton of `a`.
+///
+/// # Examples
+/// ```
+/// use algorithms::strings::longest_common_substring;
+/// assert_eq!((2, 1, 4), longest_common_substring(b"xxabcdy", b"zabcdz"));
+/// assert_eq!((0, 0, 0), longest_common_substring(b"abc", b"xyz"));
+/// ```
+pub fn longest_common_substring<T: Ord + Clone>(a: &[T], b: &[T]) -> (usize, usize, usize) {
+    let sa = SuffixAutomaton::new(a);
+    let mut best = (0, 0, 0);
+    let (mut v, mut len) = (0, 0);
+    for (j, c) in b.iter().enumerate() {
+        while v != 0 && sa.next(v, c).is_none() {
+            v = sa.states[v].link;
+            len = sa.states[v].len;
+        }
+        if let Some(u) = sa.next(v, c) {
+            v = u;
+            len += 1;
+        }
+        if len > best.2 {
+            best = (sa.states[v].first_end - len, j + 1 - len, len);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::XorShift;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_suffix_automaton() {
+        let mut rng = XorShift::new();
+        for n in 0..40 {
+            let s: Vec<u8> = (0..n).map(|_| (rng.next_u32() % 3) as u8).collect();
+            let sa = SuffixAutomaton::new(&s);
+            assert!(sa.num_states() <= std::cmp::max(2 * n, 2));
+            let substrings: BTreeSet<&[u8]> = (0..n)
+                .flat_map(|i| (i + 1..=n).map(move |j| (i, j)))
+                .map(|(i, j)| &s[i..j])
+                .collect();
+            assert_eq!(substrings.len() as u64, sa.count_distinct());
+            for (k, t) in substrings.iter().enumerate() {
+                assert_eq!(Some(t.to_vec()), sa.kth_substring(k as u64));
+                let expected = (0..=n - t.len())
+                    .filter(|&i| s[i..i + t.len()] == **t)
+                    .count();
+                assert_eq!(expected, sa.count_occurrences(t));
+                let v = sa.find(t).unwrap();
+                assert!(sa.state_len(v) >= t.len());
+                assert!(sa.state_len(sa.link(v).unwrap()) < t.len());
+            }
+            assert_eq!(None, sa.kth_substring(substrings.len() as u64));
+            assert_eq!(n + 1, sa.count_occurrences(&[]));
+            let t: Vec<u8> = (0..4).map(|_| (rng.next_u32() % 3) as u8).collect();
+            assert_eq!(substrings.contains(&t[..]), sa.find(&t).is_some());
+        }
+        assert!(SuffixAutomaton::<u8>::new(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_longest_common_substring() {
+        let mut rng = XorShift::new();
+        for _ in 0..200 {
+            let n = (rng.next_u32() % 20) as usize;
+            let m = (rng.next_u32() % 20) as usize;
+            let a: Vec<u8> = (0..n).map(|_| (rng.next_u32() % 2) as u8).collect();
+            let b: Vec<u8> = (0..m).map(|_| (rng.next_u32() % 2) as u8).collect();
+            let mut expected = 0;
+            for i in 0..n {
+                for j in 0..m {
+                    let len = a[i..]
+                        .iter()
+                        .zip(&b[j..])
+                        .take_while(|(x, y)| x == y)
+                        .count();
+                    expected = std::cmp::max(expected, len);
+                }
+            }
+            let (i, j, len) = longest_common_substring(&a, &b);
+            assert_eq!(expected, len);
+            assert_eq!(a[i..i + len], b[j..j + len]);
+        }
+        let words = vec!["to", "be", "or", "not", "to", "be"];
+        assert_eq!(
+            (0, 1, 2),
+            longest_common_substring(&words, &["x", "to", "be", "x"])
+        );
+    }
+}
@@ -1,5 +1,37 @@
-static DY: [isize; 8] = [0, 1, 0, -1, 1, -1, 1, -1];
-static DX: [isize; 8] = [1, 0, -1, 0, 1, 1, -1, -1];
+use std::ops;
+
+/// Offsets `(dy, dx)` of 4 adjacent cells (right, down, left, up).
+pub const DIR4: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// Offsets `(dy, dx)` of 8 adjacent cells. The first four are the same as [`DIR4`](constant.DIR4.html).
+pub const DIR8: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 0),
+    (0, -1),
+    (-1, 0),
+    (1, 1),
+    (-1, 1),
+    (1, -1),
+    (-1, -1),
+];
+
+/// Offsets `(dy, dx)` of knight moves in chess.
+pub const KNIGHT: [(isize, isize); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+/// Offsets `(dy, dx)` of 6 adjacent cells in a hexagonal grid with axial coordinates.
+///
+/// A cell `(y, x)` is adjacent to `(y - 1, x + 1)` and `(y + 1, x - 1)`
+/// in addition to 4 adjacent cells.
+pub const HEX: [(isize, isize); 6] = [(0, 1), (1, 0), (0, -1), (-1, 0), (-1, 1), (1, -1)];
 
 fn try_adj(y: usize, x: usize, dy: isize, dx: isize, h: usize, w: usize) -> Option<(usize, usize)> {
     let ny = y as isize + dy;
@@ -17,27 +49,40 @@ fn try_adj(y: usize, x: usize, dy: isize, dx: isize, h: usize, w: usize) -> Opti
     }
 }
 
-pub struct Adj4 {
+fn wrap_adj(y: usize, x: usize, dy: isize, dx: isize, h: usize, w: usize) -> (usize, usize) {
+    let ny = (y as isize + dy).rem_euclid(h as isize);
+    let nx = (x as isize + dx).rem_euclid(w as isize);
+    (ny as usize, nx as usize)
+}
+
+/// Iterator over adjacent cells inside a grid.
+///
+/// See [`adj_iter`](fn.adj_iter.html) and [`adj_torus_iter`](fn.adj_torus_iter.html).
+pub struct Adj<'a> {
     y: usize,
     x: usize,
     h: usize,
     w: usize,
     r: usize,
+    dirs: &'a [(isize, isize)],
+    torus: bool, // wrap around edges instead of skipping cells outside the grid
 }
 
-impl Iterator for Adj4 {
+impl<'a> Iterator for Adj<'a> {
     type Item = (usize, usize);
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if self.r >= 4 {
+            if self.r >= self.dirs.len() {
                 return None;
             }
 
-            let dy = DY[self.r];
-            let dx = DX[self.r];
+            let (dy, dx) = self.dirs[self.r];
             self.r += 1;
 
+            if self.torus {
+                return Some(wrap_adj(self.y, self.x, dy, dx, self.h, self.w));
+            }
             if let Some((ny, nx)) = try_adj(self.y, self.x, dy, dx, self.h, self.w) {
                 return Some((ny, nx));
             }
@@ -45,8 +90,296 @@ impl Iterator for Adj4 {
     }
 }
 
-pub fn adj4_iter(y: usize, x: usize, h: usize, w: usize) -> Adj4 {
-    Adj4 { y, x, h, w, r: 0 }
+/// Returns an iterator over `(y + dy, x + dx)` for each `(dy, dx)` in `dirs`
+/// skipping cells outside `h * w` grid.
+///
+/// # Examples
+/// ```
+/// use algorithms::util::{adj_iter, KNIGHT};
+/// let cells: Vec<_> = adj_iter(0, 0, 8, 8, &KNIGHT).collect();
+/// assert_eq!(vec![(1, 2), (2, 1)], cells);
+/// ```
+pub fn adj_iter(y: usize, x: usize, h: usize, w: usize, dirs: &[(isize, isize)]) -> Adj<'_> {
+    Adj {
+        y,
+        x,
+        h,
+        w,
+        r: 0,
+        dirs,
+        torus: false,
+    }
+}
+
+/// Returns an iterator over `(y + dy, x + dx)` for each `(dy, dx)` in `dirs`
+/// where coordinates wrap around edges of `h * w` grid like a torus.
+///
+/// Note that a cell may appear more than once (or be `(y, x)` itself) if the grid is small.
+///
+/// # Examples
+/// ```
+/// use algorithms::util::{adj_torus_iter, DIR4};
+/// let cells: Vec<_> = adj_torus_iter(0, 0, 3, 4, &DIR4).collect();
+/// assert_eq!(vec![(0, 1), (1, 0), (0, 3), (2, 0)], cells);
+/// ```
+pub fn adj_torus_iter(y: usize, x: usize, h: usize, w: usize, dirs: &[(isize, isize)]) -> Adj<'_> {
+    assert!(h > 0 && w > 0);
+    Adj {
+        y,
+        x,
+        h,
+        w,
+        r: 0,
+        dirs,
+        torus: true,
+    }
+}
+
+/// Returns an iterator over 4 adjacent cells of `(y, x)` inside `h * w` grid.
+pub fn adj4_iter(y: usize, x: usize, h: usize, w: usize) -> Adj<'static> {
+    adj_iter(y, x, h, w, &DIR4)
+}
+
+/// Returns an iterator over 8 adjacent cells (including diagonal ones) of `(y, x)` inside `h * w` grid.
+pub fn adj8_iter(y: usize, x: usize, h: usize, w: usize) -> Adj<'static> {
+    adj_iter(y, x, h, w, &DIR8)
+}
+
+/// Returns an iterator over `(y + dy, x + dx)` for each `(dy, dx)` in `dirs`
+/// for unbounded grids with signed coordinates.
+///
+/// # Examples
+/// ```
+/// use algorithms::util::{adj_signed_iter, HEX};
+/// let cells: Vec<_> = adj_signed_iter(0, 0, &HEX).collect();
+/// assert_eq!(vec![(0, 1), (1, 0), (0, -1), (-1, 0), (-1, 1), (1, -1)], cells);
+/// ```
+pub fn adj_signed_iter<'a>(
+    y: isize,
+    x: isize,
+    dirs: &'a [(isize, isize)],
+) -> impl Iterator<Item = (isize, isize)> + 'a {
+    dirs.iter().map(move |&(dy, dx)| (y + dy, x + dx))
+}
+
+/// Two dimensional grid of `h * w` cells.
+///
+/// Cells are stored in row-major order and a cell `(y, x)` has a flat id `y * w + x`
+/// so that grid problems can be passed to graph algorithms.
+///
+/// # Examples
+/// ```
+/// use algorithms::util::Grid;
+/// let grid = Grid::from_lines(&[
+///     "S.#",
+///     ".##",
+///     "..G",
+/// ]);
+/// let start = grid.find(b'S').unwrap();
+/// let goal = grid.find(b'G').unwrap();
+/// let dist = grid.bfs(&[start], |&c| c != b'#');
+/// assert_eq!(Some(4), dist[goal]);
+/// assert_eq!(None, dist[(0, 2)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    h: usize,
+    w: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order.
+    ///
+    /// # Panics
+    /// Panics if `cells.len() != h * w`.
+    pub fn new(h: usize, w: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(h * w, cells.len());
+        Grid { h, w, cells }
+    }
+
+    /// Creates a grid from rows.
+    ///
+    /// # Panics
+    /// Panics if rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let h = rows.len();
+        let w = rows.first().map_or(0, |r| r.len());
+        let mut cells = Vec::with_capacity(h * w);
+        for row in rows {
+            assert_eq!(w, row.len());
+            cells.extend(row);
+        }
+        Grid { h, w, cells }
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    /// Returns the flat id of a cell `(y, x)`.
+    pub fn id(&self, y: usize, x: usize) -> usize {
+        assert!(y < self.h && x < self.w);
+        y * self.w + x
+    }
+
+    /// Returns a cell `(y, x)` whose flat id is `id`.
+    pub fn pos(&self, id: usize) -> (usize, usize) {
+        assert!(id < self.cells.len());
+        (id / self.w, id % self.w)
+    }
+
+    /// Returns an iterator over 4 adjacent cells of `(y, x)`.
+    pub fn adj4(&self, y: usize, x: usize) -> Adj<'static> {
+        adj4_iter(y, x, self.h, self.w)
+    }
+
+    /// Returns an iterator over 8 adjacent cells of `(y, x)`.
+    pub fn adj8(&self, y: usize, x: usize) -> Adj<'static> {
+        adj8_iter(y, x, self.h, self.w)
+    }
+
+    /// Returns an iterator over cells `(y + dy, x + dx)` inside the grid for each `(dy, dx)` in `dirs`.
+    pub fn adj<'a>(&self, y: usize, x: usize, dirs: &'a [(isize, isize)]) -> Adj<'a> {
+        adj_iter(y, x, self.h, self.w, dirs)
+    }
+
+    /// Returns shortest distances from the nearest cell of `sources`
+    /// moving to 4 adjacent cells where `passable` returns `true`.
+    /// Distance is `None` if the cell is unreachable.
+    ///
+    /// O(hw)
+    pub fn bfs<F>(&self, sources: &[(usize, usize)], passable: F) -> Grid<Option<usize>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut dist = Grid::new(self.h, self.w, vec![None; self.cells.len()]);
+        let mut queue = Vec::with_capacity(self.cells.len());
+        for &(y, x) in sources {
+            if dist[(y, x)].is_none() {
+                dist[(y, x)] = Some(0);
+                queue.push((y, x));
+            }
+        }
+        let mut head = 0;
+        while head < queue.len() {
+            let (y, x) = queue[head];
+            head += 1;
+            let d = dist[(y, x)].unwrap();
+            for (ny, nx) in self.adj4(y, x) {
+                if dist[(ny, nx)].is_none() && passable(&self[(ny, nx)]) {
+                    dist[(ny, nx)] = Some(d + 1);
+                    queue.push((ny, nx));
+                }
+            }
+        }
+        dist
+    }
+
+    /// Labels connected components of cells where `passable` returns `true`.
+    /// Two cells are connected if they are 4-adjacent.
+    ///
+    /// Returns the number of components and labels of cells.
+    /// Labels are `0, 1, ...` in row-major order of the first cell of each component,
+    /// and `None` for cells where `passable` returns `false`.
+    ///
+    /// O(hw)
+    pub fn components<F>(&self, passable: F) -> (usize, Grid<Option<usize>>)
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut label = Grid::new(self.h, self.w, vec![None; self.cells.len()]);
+        let mut count = 0;
+        let mut stack = Vec::new();
+        for id in 0..self.cells.len() {
+            if label.cells[id].is_some() || !passable(&self.cells[id]) {
+                continue;
+            }
+            label.cells[id] = Some(count);
+            stack.push(self.pos(id));
+            while let Some((y, x)) = stack.pop() {
+                for (ny, nx) in self.adj4(y, x) {
+                    if label[(ny, nx)].is_none() && passable(&self[(ny, nx)]) {
+                        label[(ny, nx)] = Some(count);
+                        stack.push((ny, nx));
+                    }
+                }
+            }
+            count += 1;
+        }
+        (count, label)
+    }
+
+    /// Converts the grid into adjacency lists indexed by flat ids.
+    /// Edges connect 4-adjacent cells where `passable` returns `true` for both.
+    pub fn to_graph<F>(&self, passable: F) -> Vec<Vec<usize>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut graph = vec![Vec::new(); self.cells.len()];
+        for (id, edges) in graph.iter_mut().enumerate() {
+            let (y, x) = self.pos(id);
+            if !passable(&self[(y, x)]) {
+                continue;
+            }
+            for (ny, nx) in self.adj4(y, x) {
+                if passable(&self[(ny, nx)]) {
+                    edges.push(self.id(ny, nx));
+                }
+            }
+        }
+        graph
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the first cell in row-major order whose value is `value`.
+    pub fn find(&self, value: T) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(|c| *c == value)
+            .map(|id| self.pos(id))
+    }
+}
+
+impl Grid<u8> {
+    /// Creates a grid of bytes from lines such as `["..#", "#.."]`.
+    ///
+    /// # Panics
+    /// Panics if lines have different lengths.
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Grid<u8> {
+        Grid::from_rows(
+            lines
+                .iter()
+                .map(|line| line.as_ref().as_bytes().to_vec())
+                .collect(),
+        )
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(rows: Vec<Vec<T>>) -> Grid<T> {
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> ops::Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (y, x): (usize, usize)) -> &T {
+        &self.cells[self.id(y, x)]
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (y, x): (usize, usize)) -> &mut T {
+        let id = self.id(y, x);
+        &mut self.cells[id]
+    }
 }
 
 #[cfg(test)]
@@ -78,4 +411,80 @@ mod test {
         assert_eq!(Some((2, 3)), iter.next());
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn test_adj8() {
+        let iter = adj8_iter(0, 1, 2, 3);
+        assert_eq!(
+            vec![(0, 2), (1, 1), (0, 0), (1, 2), (1, 0)],
+            iter.collect::<Vec<_>>()
+        );
+        assert_eq!(8, adj8_iter(1, 1, 3, 3).count());
+    }
+
+    #[test]
+    fn test_grid_bfs() {
+        let grid = Grid::from_lines(&["S..#", ".#.#", "S#.."]);
+        let dist = grid.bfs(&[(0, 0), (2, 0)], |&c| c != b'#');
+        assert_eq!(Some(0), dist[(2, 0)]);
+        assert_eq!(Some(1), dist[(1, 0)]);
+        assert_eq!(Some(2), dist[(0, 2)]);
+        assert_eq!(Some(5), dist[(2, 3)]);
+        assert_eq!(None, dist[(0, 3)]);
+        assert_eq!(None, dist[(1, 1)]);
+    }
+
+    #[test]
+    fn test_grid_components() {
+        let grid: Grid<u8> = vec![b"..#.".to_vec(), b"##..".to_vec(), b"..#.".to_vec()].into();
+        let (count, label) = grid.components(|&c| c == b'.');
+        assert_eq!(3, count);
+        assert_eq!(Some(0), label[(0, 1)]);
+        assert_eq!(Some(1), label[(0, 3)]);
+        assert_eq!(Some(1), label[(2, 3)]);
+        assert_eq!(Some(2), label[(2, 1)]);
+        assert_eq!(None, label[(1, 0)]);
+    }
+
+    #[test]
+    fn test_grid_to_graph() {
+        let grid = Grid::from_lines(&[".#", ".."]);
+        assert_eq!((1, 0), grid.pos(2));
+        assert_eq!(3, grid.id(1, 1));
+        let graph = grid.to_graph(|&c| c != b'#');
+        assert_eq!(vec![vec![2], vec![], vec![3, 0], vec![2]], graph);
+    }
+
+    #[test]
+    fn test_knight() {
+        assert_eq!(8, adj_iter(3, 3, 8, 8, &KNIGHT).count());
+        let cells: Vec<_> = adj_iter(7, 6, 8, 8, &KNIGHT).collect();
+        assert_eq!(vec![(6, 4), (5, 5), (5, 7)], cells);
+    }
+
+    #[test]
+    fn test_torus() {
+        let cells: Vec<_> = adj_torus_iter(2, 3, 3, 4, &DIR8).collect();
+        assert_eq!(
+            vec![
+                (2, 0),
+                (0, 3),
+                (2, 2),
+                (1, 3),
+                (0, 0),
+                (1, 0),
+                (0, 2),
+                (1, 2)
+            ],
+            cells
+        );
+        // a cell in 1 * 1 grid is adjacent to itself
+        assert!(adj_torus_iter(0, 0, 1, 1, &DIR4).all(|c| c == (0, 0)));
+    }
+
+    #[test]
+    fn test_signed() {
+        let cells: Vec<_> = adj_signed_iter(-5, 3, &DIR4).collect();
+        assert_eq!(vec![(-5, 4), (-4, 3), (-5, 2), (-6, 3)], cells);
+    }
 }
@@ -1,5 +1,6 @@
 //! util functions and trails to help typical implementation
 //!
+//! * [`Grid`](struct.Grid.html) - Two dimensional grid with BFS and connected components.
 //! * [`Permutation`](trait.Permutation.html) - Add `next_permutation`, `prev_permutation` for slices.
 //! * [`XorShift`](struct.XorShift.html) - Provide very simple random generator.
 //!
@@ -9,6 +10,7 @@ mod permutation;
 mod random;
 
 pub use self::binary_search::*;
-pub use self::grid::adj4_iter;
+pub use self::grid::{adj4_iter, adj8_iter, adj_iter, adj_signed_iter, adj_torus_iter, Grid};
+pub use self::grid::{DIR4, DIR8, HEX, KNIGHT};
 pub use self::permutation::Permutation;
 pub use self::random::XorShift;
@@ -28,6 +28,27 @@ impl XorShift {
         }
     }
 
+    /// Create object whose state is mixed with `seed`.
+    ///
+    /// # Examples
+    /// ```
+    /// use algorithms::util::XorShift;
+    /// let mut a = XorShift::with_seed(42);
+    /// let mut b = XorShift::with_seed(42);
+    /// assert_eq!(a.next_u32(), b.next_u32());
+    /// assert_ne!(XorShift::new().next_u32(), XorShift::with_seed(1).next_u32());
+    /// ```
+    pub fn with_seed(seed: u64) -> XorShift {
+        let mut res = XorShift::new();
+        res.x ^= Wrapping(seed as u32);
+        res.y ^= Wrapping((seed >> 32) as u32);
+        // discard first outputs which are close for close seeds
+        for _ in 0..16 {
+            res.next_u32();
+        }
+        res
+    }
+
     /// Get next random u32 number.
     pub fn next_u32(&mut self) -> u32 {
         let x = self.x;